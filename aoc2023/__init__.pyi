@@ -0,0 +1,170 @@
+# generated by `aoc2023 stub`, do not edit
+import os
+from typing import IO, TYPE_CHECKING, Any, Literal, Optional, TypedDict, Union
+
+if TYPE_CHECKING:
+    # numpy is only needed with the `numpy` feature, it is not a dependency of the package
+    import numpy as np
+
+Input = Union[str, bytes, os.PathLike[str], IO[str], IO[bytes]]
+"""A puzzle input.
+
+A `str` is the puzzle text, a path has to be an `os.PathLike` like `pathlib.Path`. `bytes` is the
+puzzle text as utf-8. Anything with a `.read()` method, like an open file, is read.
+"""
+
+Part = Literal["a", "b"]
+HandTuple = tuple[int, int, int]
+"""`(red, green, blue)`"""
+
+class AocError(ValueError): ...
+
+class ParseError(AocError):
+    day: int
+    line: int
+    column: int
+    text: str
+    expected: str
+
+class InputError(AocError): ...
+
+class UnsolvableError(AocError):
+    day: int
+
+class NotSolvedError(AocError):
+    day: int
+    part: Part
+
+class PanicError(AocError):
+    day: int
+
+class BenchmarkReport(TypedDict):
+    day: int
+    part: Part
+    name: str
+    answer: int
+    runs: int
+    min: float
+    median: float
+    max: float
+    allocations: Optional[int]
+
+class Verification(TypedDict):
+    day: int
+    part: Part
+    input: str
+    expected: int
+    status: Literal["pass", "fail", "unsolved"]
+    message: Optional[str]
+
+class SolverRun(TypedDict):
+    day: int
+    part: Part
+    status: Literal["solved", "unsolved", "error"]
+    answer: Optional[int]
+    error: Optional[str]
+    seconds: float
+    cached: bool
+
+class Explanation(TypedDict):
+    line: int
+    text: str
+    parsed: Any
+    contribution: int
+    details: dict[str, Any]
+
+class CacheEntry(TypedDict):
+    day: int
+    part: Part
+    name: str
+    version: str
+    input: str
+    answer: int
+
+class Tokenizer:
+    @property
+    def tokens(self) -> dict[tuple[int, int], Union[int, str]]:
+        """The numbers and the symbols by `(x, y)`, gears are `"*"` and other symbols `"#"`."""
+    def to_numpy(self) -> np.ndarray:
+        """The schematic as int8 `[y][x]`, the digits, -1 empty, -2 a symbol and -3 a gear.
+
+        Only with the `numpy` feature.
+        """
+
+def solvers() -> list[tuple[int, Part, str, Literal["solved", "unsolved"]]]: ...
+
+def solve(day: int, part: Part, input: Input) -> int: ...
+
+def run_all(paths: dict[int, Input]) -> dict[str, SolverRun]: ...
+
+def benchmark(day: int, part: Part, runs: int = 10, input: Optional[Input] = None) -> BenchmarkReport: ...
+
+def verify(manifest: Union[str, os.PathLike[str]] = "data/answers.json") -> list[Verification]: ...
+
+def generate(day: int, seed: int, size: int) -> str: ...
+
+def explain(day: int, part: Part, input: Input) -> list[Explanation]: ...
+
+def render(day: int, input: Input, format: Literal["ansi", "svg"] = "ansi") -> str: ...
+
+def cache_enable(directory: Union[str, os.PathLike[str]]) -> None: ...
+
+def cache_disable() -> None: ...
+
+def cache_entries() -> list[CacheEntry]: ...
+
+def cache_clear() -> int: ...
+
+def logging_refresh() -> None: ...
+
+def day02_parse_hand(input: str) -> HandTuple: ...
+
+def day02_parse_game(input: str) -> tuple[int, list[HandTuple]]: ...
+
+def day03a_parse_tokens(schema: str) -> Tokenizer: ...
+
+def day07_sort_cards(cards_text: str) -> list[list[int]]: ...
+
+def to_numpy(day: int, input: Input) -> np.ndarray: ...
+
+def day06_ways_to_win(times: np.ndarray, distances: np.ndarray) -> np.ndarray: ...
+
+def day09_extrapolate(histories: np.ndarray, part: Part = "a") -> np.ndarray: ...
+
+def day01_parse(input: Input) -> dict[str, Any]: ...
+def day02_parse(input: Input) -> dict[str, Any]: ...
+def day03_parse(input: Input) -> dict[str, Any]: ...
+def day04_parse(input: Input) -> dict[str, Any]: ...
+def day05_parse(input: Input) -> dict[str, Any]: ...
+def day06_parse(input: Input) -> dict[str, Any]: ...
+def day07_parse(input: Input) -> dict[str, Any]: ...
+def day08_parse(input: Input) -> dict[str, Any]: ...
+def day09_parse(input: Input) -> dict[str, Any]: ...
+def day10_parse(input: Input) -> dict[str, Any]: ...
+def day11_parse(input: Input) -> dict[str, Any]: ...
+def day12_parse(input: Input) -> dict[str, Any]: ...
+
+def day01a(input: Input) -> int: ...
+def day01b(input: Input) -> int: ...
+def day02a(input: Input) -> int: ...
+def day02b(input: Input) -> int: ...
+def day03a(input: Input) -> int: ...
+def day03b(input: Input) -> int: ...
+def day04a(input: Input) -> int: ...
+def day04b(input: Input) -> int: ...
+def day05a(input: Input) -> int: ...
+def day05b(input: Input) -> int: ...
+def day06a(input: Input) -> int: ...
+def day06b(input: Input) -> int: ...
+def day07a(input: Input) -> int: ...
+def day07b(input: Input) -> int: ...
+def day08a(input: Input) -> int: ...
+def day08b(input: Input) -> int: ...
+def day09a(input: Input) -> int: ...
+def day09b(input: Input) -> int: ...
+def day10a(input: Input) -> int: ...
+def day10b(input: Input) -> int: ...
+def day11a(input: Input) -> int: ...
+def day11b(input: Input) -> int: ...
+def day12a(input: Input) -> int: ...
+def day12b(input: Input) -> int: ...
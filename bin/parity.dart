@@ -0,0 +1,33 @@
+/// `dart run bin/parity.dart DAY PART`, reads the puzzle from stdin and prints the answer.
+///
+/// Exits with [notImplemented] when there is no dart solution for the part, see
+/// `src/parity.rs`.
+library;
+
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:aoc2023/day01.dart' as day01;
+
+const notImplemented = 3;
+
+/// The dart solutions by day and part, like `'1a'`.
+final solutions = <String, int Function(String input)>{
+  '1a': day01.day01a,
+  '1b': day01.day01b,
+};
+
+Future<void> main(List<String> arguments) async {
+  if (arguments.length != 2) {
+    stderr.writeln('usage: dart run bin/parity.dart DAY PART');
+    exit(2);
+  }
+
+  final solve = solutions['${int.parse(arguments[0])}${arguments[1]}'];
+  if (solve == null) {
+    exit(notImplemented);
+  }
+
+  final input = await stdin.transform(utf8.decoder).join();
+  print(solve(input));
+}
@@ -0,0 +1,14 @@
+//! The fuzz targets, one per day: `cargo +nightly fuzz run day10`.
+//!
+//! Every target parses the input as the puzzle of its day and runs both parts on it. Errors are
+//! fine, panics are not. Inputs that crashed are kept in `regressions/`, `tests/regressions.rs`
+//! of the main crate runs them.
+
+use aoc2023::{model, solver};
+
+pub fn check(day: u8, input: &str) {
+    let _ = model::parse(day, input);
+    for solver in solver::solvers().iter().filter(|solver| solver.day() == day) {
+        let _ = solver.solve(input);
+    }
+}
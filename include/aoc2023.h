@@ -0,0 +1,98 @@
+/* generated by `aoc2023 header`, do not edit */
+
+#ifndef AOC2023_H
+#define AOC2023_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+/* the solvers, `day` and `part` of aoc_solve:
+ * 1 a day01a
+ * 1 b day01b
+ * 2 a day02a
+ * 2 b day02b
+ * 3 a day03a
+ * 3 b day03b
+ * 4 a day04a
+ * 4 b day04b
+ * 5 a day05a
+ * 5 b day05b
+ * 6 a day06a
+ * 6 b day06b
+ * 7 a day07a
+ * 7 b day07b (not solved yet)
+ * 8 a day08a
+ * 8 b day08b
+ * 9 a day09a
+ * 9 b day09b
+ * 10 a day10a
+ * 10 b day10b (not solved yet)
+ * 11 a day11a (not solved yet)
+ * 11 b day11b (not solved yet)
+ * 12 a day12a (not solved yet)
+ * 12 b day12b (not solved yet)
+ */
+
+/**
+ * the `status` of an [`AocResult`] with an answer
+ */
+#define AOC_OK 0
+
+#define AOC_PARSE_ERROR 1
+
+#define AOC_INPUT_ERROR 2
+
+#define AOC_UNSOLVABLE 3
+
+#define AOC_NOT_SOLVED 4
+
+#define AOC_PANIC 5
+
+/**
+ * `status` is [`AOC_OK`] or one of the errors, `text` is `length` bytes of utf-8 without a
+ * trailing 0
+ */
+typedef struct AocResult {
+  int32_t status;
+  uint8_t *text;
+  size_t length;
+} AocResult;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * solves `part`, `'a'` or `'b'`, of `day`. the text is the answer as a decimal number
+ *
+ * # Safety
+ * `input` points to `length` readable bytes, it may be null when `length` is 0
+ */
+struct AocResult aoc_solve(uint8_t day, uint8_t part, const uint8_t *input, size_t length);
+
+/**
+ * the parsed puzzle of `day` as JSON, like `aoc2023 parse`
+ *
+ * # Safety
+ * `input` points to `length` readable bytes, it may be null when `length` is 0
+ */
+struct AocResult aoc_parse(uint8_t day, const uint8_t *input, size_t length);
+
+/**
+ * the solvers as a JSON list of `{"day": 1, "part": "a", "name": "day01a", "solved": true}`
+ */
+struct AocResult aoc_solvers(void);
+
+/**
+ * frees the text of a result, every result should be freed once
+ *
+ * # Safety
+ * `result` is returned by one of the functions above and is not freed yet
+ */
+void aoc_result_free(struct AocResult result);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* AOC2023_H */
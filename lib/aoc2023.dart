@@ -0,0 +1,132 @@
+/// The rust solvers, over the C functions of `include/aoc2023.h`.
+///
+/// The shared library is built by `cargo build --release`, [Aoc2023.open] loads it from
+/// `target/release` unless it gets another path.
+library;
+
+import 'dart:convert';
+import 'dart:ffi';
+import 'dart:io';
+import 'dart:math';
+
+import 'package:ffi/ffi.dart';
+
+/// `AocResult` of the header, [text] is [length] bytes of utf-8.
+final class AocResult extends Struct {
+  @Int32()
+  external int status;
+
+  external Pointer<Uint8> text;
+
+  @Size()
+  external int length;
+}
+
+/// The `AOC_*` statuses of the header, in the same order.
+enum AocStatus { ok, parseError, inputError, unsolvable, notSolved, panic }
+
+class AocException implements Exception {
+  final AocStatus status;
+  final String message;
+
+  AocException(this.status, this.message);
+
+  @override
+  String toString() => 'AocException(${status.name}): $message';
+}
+
+class Solver {
+  final int day;
+  final String part;
+  final String name;
+  final bool solved;
+
+  Solver(this.day, this.part, this.name, this.solved);
+}
+
+typedef _SolveNative = AocResult Function(
+    Uint8 day, Uint8 part, Pointer<Uint8> input, Size length);
+typedef _Solve = AocResult Function(
+    int day, int part, Pointer<Uint8> input, int length);
+typedef _ParseNative = AocResult Function(
+    Uint8 day, Pointer<Uint8> input, Size length);
+typedef _Parse = AocResult Function(int day, Pointer<Uint8> input, int length);
+typedef _Solvers = AocResult Function();
+typedef _FreeNative = Void Function(AocResult result);
+typedef _Free = void Function(AocResult result);
+
+class Aoc2023 {
+  final _Solve _solve;
+  final _Parse _parse;
+  final _Solvers _solvers;
+  final _Free _free;
+
+  Aoc2023(DynamicLibrary library)
+      : _solve = library.lookupFunction<_SolveNative, _Solve>('aoc_solve'),
+        _parse = library.lookupFunction<_ParseNative, _Parse>('aoc_parse'),
+        _solvers = library.lookupFunction<_Solvers, _Solvers>('aoc_solvers'),
+        _free = library.lookupFunction<_FreeNative, _Free>('aoc_result_free');
+
+  factory Aoc2023.open([String? path]) =>
+      Aoc2023(DynamicLibrary.open(path ?? defaultLibraryPath()));
+
+  /// Where `cargo build --release` puts the library.
+  static String defaultLibraryPath() {
+    if (Platform.isWindows) {
+      return 'target/release/aoc2023.dll';
+    } else if (Platform.isMacOS) {
+      return 'target/release/libaoc2023.dylib';
+    } else {
+      return 'target/release/libaoc2023.so';
+    }
+  }
+
+  /// Solves [part], `'a'` or `'b'`, of [day]. [input] is the puzzle text, not a path.
+  int solve(int day, String part, String input) {
+    final answer = _withInput(input,
+        (pointer, length) => _solve(day, part.codeUnitAt(0), pointer, length));
+    return int.parse(answer);
+  }
+
+  /// The parsed puzzle of [day], decoded from its JSON.
+  Object? parse(int day, String input) {
+    final json =
+        _withInput(input, (pointer, length) => _parse(day, pointer, length));
+    return jsonDecode(json);
+  }
+
+  List<Solver> solvers() {
+    final solvers = jsonDecode(_take(_solvers())) as List<dynamic>;
+    return [
+      for (final solver in solvers)
+        Solver(solver['day'] as int, solver['part'] as String,
+            solver['name'] as String, solver['solved'] as bool)
+    ];
+  }
+
+  String _withInput(String input,
+      AocResult Function(Pointer<Uint8> pointer, int length) call) {
+    final bytes = utf8.encode(input);
+    // malloc of 0 bytes may give a null pointer
+    final pointer = malloc<Uint8>(max(bytes.length, 1));
+    try {
+      pointer.asTypedList(bytes.length).setAll(0, bytes);
+      return _take(call(pointer, bytes.length));
+    } finally {
+      malloc.free(pointer);
+    }
+  }
+
+  /// The text of [result], an [AocException] if it is an error. Frees [result].
+  String _take(AocResult result) {
+    try {
+      final text = utf8.decode(result.text.asTypedList(result.length));
+      if (result.status != AocStatus.ok.index) {
+        throw AocException(AocStatus.values[result.status], text);
+      }
+      return text;
+    } finally {
+      _free(result);
+    }
+  }
+}
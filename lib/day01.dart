@@ -0,0 +1,57 @@
+/// Day 1, ported from `src/day01.rs`.
+library;
+
+const _digitWords = [
+  'one',
+  'two',
+  'three',
+  'four',
+  'five',
+  'six',
+  'seven',
+  'eight',
+  'nine',
+];
+
+int _calibration(List<int> digits) => digits.first * 10 + digits.last;
+
+bool _isDigit(int codeUnit) => codeUnit >= 0x30 && codeUnit <= 0x39;
+
+Iterable<String> _lines(String input) =>
+    input.split('\n').where((line) => line.trim().isNotEmpty);
+
+int day01a(String input) {
+  var sum = 0;
+  for (final line in _lines(input)) {
+    sum += _calibration([
+      for (final codeUnit in line.codeUnits)
+        if (_isDigit(codeUnit)) codeUnit - 0x30
+    ]);
+  }
+  return sum;
+}
+
+/// The digits and the spelled out digits of [line], words can overlap like in "twone".
+List<int> _digitsAndWords(String line) {
+  final digits = <int>[];
+  for (var index = 0; index < line.length; index++) {
+    final codeUnit = line.codeUnitAt(index);
+    if (_isDigit(codeUnit)) {
+      digits.add(codeUnit - 0x30);
+    }
+    for (var word = 0; word < _digitWords.length; word++) {
+      if (line.startsWith(_digitWords[word], index)) {
+        digits.add(word + 1);
+      }
+    }
+  }
+  return digits;
+}
+
+int day01b(String input) {
+  var sum = 0;
+  for (final line in _lines(input)) {
+    sum += _calibration(_digitsAndWords(line));
+  }
+  return sum;
+}
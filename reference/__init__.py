@@ -0,0 +1,5 @@
+"""Python reference implementations, to compare with the rust solvers.
+
+`reference/dayNN.py` has `part_a(text)` and `part_b(text)`, `text` is the puzzle input. Run them
+with `python -m reference DAY PART < input` or through `aoc2023 parity`.
+"""
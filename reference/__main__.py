@@ -0,0 +1,30 @@
+"""`python -m reference DAY PART`, reads the puzzle from stdin and prints the answer.
+
+Exits with `NOT_IMPLEMENTED` when there is no reference for the part, see `src/parity.rs`.
+"""
+import importlib
+import sys
+
+NOT_IMPLEMENTED = 3
+
+
+def main(arguments):
+    day, part = int(arguments[0]), arguments[1]
+    name = f"reference.day{day:02}"
+    try:
+        module = importlib.import_module(name)
+    except ModuleNotFoundError as error:
+        if error.name != name:
+            raise
+        return NOT_IMPLEMENTED
+
+    solve = getattr(module, f"part_{part}", None)
+    if solve is None:
+        return NOT_IMPLEMENTED
+
+    print(solve(sys.stdin.read()))
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main(sys.argv[1:]))
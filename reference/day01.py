@@ -0,0 +1,28 @@
+DIGIT_WORDS = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"]
+
+
+def calibration(digits):
+    return digits[0] * 10 + digits[-1]
+
+
+def part_a(text):
+    return sum(
+        calibration([int(ch) for ch in line if ch.isdigit()])
+        for line in text.splitlines()
+    )
+
+
+def digits_and_words(line):
+    # the words can overlap, like "twone", so every position is checked
+    for index, ch in enumerate(line):
+        if ch.isdigit():
+            yield int(ch)
+        for value, word in enumerate(DIGIT_WORDS, start=1):
+            if line.startswith(word, index):
+                yield value
+
+
+def part_b(text):
+    return sum(
+        calibration(list(digits_and_words(line))) for line in text.splitlines()
+    )
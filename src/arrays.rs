@@ -0,0 +1,146 @@
+//! The grid days as dense arrays, for numpy.
+//!
+//! Every cell of the puzzle becomes one number, row by row, so `array[y][x]` is the cell at
+//! `(x, y)`. The python bindings turn these into ndarrays when the `numpy` feature is on, the
+//! arrays themselves do not need it.
+
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::{day03, day06, day09, day10, day11, day12};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Array {
+    Int8(Grid<i8>),
+    UInt8(Grid<u8>),
+    Bool(Grid<bool>),
+    Int64(Grid<i64>),
+}
+
+impl Array {
+    /// `(rows, columns)`, the shape numpy uses
+    pub fn shape(&self) -> (usize, usize) {
+        match self {
+            Array::Int8(grid) => (grid.height(), grid.width()),
+            Array::UInt8(grid) => (grid.height(), grid.width()),
+            Array::Bool(grid) => (grid.height(), grid.width()),
+            Array::Int64(grid) => (grid.height(), grid.width()),
+        }
+    }
+}
+
+/// the days with an array and what the cells are
+pub const ARRAYS: &[(u8, &str)] = &[
+    (3, "int8, the digits, -1 empty, -2 a symbol and -3 a gear"),
+    (6, "int64, a row per race of [time, distance]"),
+    (9, "int64, a row per history"),
+    (10, "uint8, the ascii code of the tile"),
+    (11, "bool, true where there is a galaxy"),
+    (
+        12,
+        "int8, 0 operational, 1 damaged, 2 unknown and -1 after the end of the row",
+    ),
+];
+
+/// the puzzle of `day` as an array
+pub fn to_array(day: u8, input: &str) -> Result<Array, AocError> {
+    match day {
+        3 => Ok(Array::Int8(day03::day03a_parse_tokens(input)?.codes())),
+        6 => Ok(Array::Int64(day06::day06_races(input)?)),
+        9 => Ok(Array::Int64(day09::day09_histories(input)?)),
+        10 => Ok(Array::UInt8(day10::day10_tiles(input)?)),
+        11 => Ok(Array::Bool(day11::day11_galaxies(input)?)),
+        12 => Ok(Array::Int8(day12::day12_springs(input)?)),
+        _ => Err(AocError::Input(format!("day {} has no array", day))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(day: u8) -> String {
+        std::fs::read_to_string(format!("data/day{:02}_debug.txt", day)).unwrap()
+    }
+
+    #[test]
+    fn every_sample() {
+        for &(day, _) in ARRAYS {
+            let array = to_array(day, &sample(day)).unwrap();
+            let lines = sample(day).lines().count();
+            match day {
+                // a row per race, the times and the distances are on two lines
+                6 => assert_eq!(2, array.shape().1),
+                _ => assert_eq!(lines, array.shape().0, "day {}", day),
+            }
+        }
+    }
+
+    #[test]
+    fn day03_codes() {
+        let Array::Int8(grid) = to_array(3, "467.\n..*.\n.#5.").unwrap() else {
+            panic!("day 3 is an int8 array")
+        };
+        let rows: Vec<_> = grid.rows().collect();
+        assert_eq!(vec![[4, 6, 7, -1], [-1, -1, -3, -1], [-1, -2, 5, -1]], rows);
+    }
+
+    #[test]
+    fn day06_races() {
+        let Array::Int64(grid) = to_array(6, &sample(6)).unwrap() else {
+            panic!("day 6 is an int64 array")
+        };
+        assert_eq!(
+            vec![[7, 9], [15, 40], [30, 200]],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![4, 8, 9],
+            day06::day06_ways_to_win(&[7, 15, 30], &[9, 40, 200]).unwrap()
+        );
+        assert!(day06::day06_ways_to_win(&[7], &[]).is_err());
+    }
+
+    #[test]
+    fn day09_histories() {
+        assert!(matches!(to_array(9, "1 2 3\n1 2"), Err(AocError::Input(_))));
+        assert_eq!(
+            vec![18, 28, 68],
+            day09::day09_extrapolate(
+                vec![
+                    vec![0, 3, 6, 9, 12, 15],
+                    vec![1, 3, 6, 10, 15, 21],
+                    vec![10, 13, 16, 21, 30, 45]
+                ],
+                crate::solver::Part::A
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn day11_galaxies() {
+        let Array::Bool(grid) = to_array(11, "#..\n..#").unwrap() else {
+            panic!("day 11 is a bool array")
+        };
+        assert_eq!(
+            vec![[true, false, false], [false, false, true]],
+            grid.rows().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn day12_padding() {
+        let Array::Int8(grid) = to_array(12, "#.? 1,1\n# 1").unwrap() else {
+            panic!("day 12 is an int8 array")
+        };
+        assert_eq!(
+            vec![[1, 0, 2], [1, -1, -1]],
+            grid.rows().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn no_array() {
+        assert!(matches!(to_array(1, "1abc2"), Err(AocError::Input(_))));
+    }
+}
@@ -0,0 +1,142 @@
+//! Runs a solver a number of times and reports how long it took and how much it allocated.
+//!
+//! Allocations are only counted when the program installs [`CountingAllocator`] as its global
+//! allocator, the library never does that itself. The binary does it with the opt-in
+//! `count-allocations` feature. The counter is shared by all threads, so benchmarks should not
+//! run next to other work.
+
+use crate::error::{catch_panic, AocError};
+use crate::solver::{Answer, Part, Solver};
+use serde::{Serialize, Serializer};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_RUNS: usize = 10;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+/// set by the first allocation through [`CountingAllocator`], so only when it is installed
+static COUNTING: AtomicBool = AtomicBool::new(false);
+
+/// whether [`CountingAllocator`] is the global allocator of this program
+pub fn counts_allocations() -> bool {
+    COUNTING.load(Ordering::Relaxed)
+}
+
+/// the system allocator, but counts how often it is called. install it in a binary with
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        COUNTING.store(true, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: Part,
+    pub name: &'static str,
+    pub answer: Answer,
+    pub runs: usize,
+    #[serde(serialize_with = "as_seconds")]
+    pub min: Duration,
+    #[serde(serialize_with = "as_seconds")]
+    pub median: Duration,
+    #[serde(serialize_with = "as_seconds")]
+    pub max: Duration,
+    /// allocations of a single run, `None` if they are not counted
+    pub allocations: Option<usize>,
+}
+
+fn as_seconds<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+/// solves `input` `runs` times, stops at the first error
+pub fn benchmark(solver: &dyn Solver, input: &str, runs: usize) -> Result<Report, AocError> {
+    if runs == 0 {
+        return Err(AocError::Input(String::from("runs should be at least 1")));
+    }
+
+    let mut timings = Vec::with_capacity(runs);
+    let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+    let mut answer = None;
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let result = catch_panic(solver.day(), || solver.solve(input))?;
+        timings.push(start.elapsed());
+        answer = Some(result);
+    }
+
+    // the timings vec does not grow in the loop, so every counted allocation is of the solver
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+    timings.sort();
+
+    Ok(Report {
+        day: solver.day(),
+        part: solver.part(),
+        name: solver.name(),
+        answer: answer.expect("runs is at least 1"),
+        runs,
+        min: timings[0],
+        median: timings[runs / 2],
+        max: timings[runs - 1],
+        allocations: counts_allocations().then_some(allocations / runs),
+    })
+}
+
+pub fn to_json(reports: &[Report]) -> String {
+    serde_json::to_string_pretty(reports).expect("reports only contain plain data")
+}
+
+pub fn to_markdown(reports: &[Report]) -> String {
+    let mut table = String::from(
+        "| day | part | answer | runs | min | median | max | allocations |\n\
+         |----:|:----:|-------:|-----:|----:|-------:|----:|------------:|\n",
+    );
+
+    for report in reports {
+        let allocations = report
+            .allocations
+            .map_or_else(|| String::from("-"), |count| count.to_string());
+        writeln!(
+            table,
+            "| {:02} | {} | {} | {} | {:?} | {:?} | {:?} | {} |",
+            report.day,
+            report.part,
+            report.answer,
+            report.runs,
+            report.min,
+            report.median,
+            report.max,
+            allocations
+        )
+        .expect("writing to a string does not fail");
+    }
+
+    table
+}
@@ -0,0 +1,270 @@
+//! An on-disk cache of the answers, so a rerun of the same input does not solve it again.
+//!
+//! The cache is off until [`enable`] gives it a directory, the library never turns it on by
+//! itself. The command line and the python module call it with [`ENV`] when that is set. An
+//! entry is keyed by the day, the part, the version of the solver and a hash of the normalized
+//! input, so line endings and trailing whitespace do not miss the cache. The version is a hash of
+//! the source of the day and the modules it shares with the other days, so changing a solver
+//! makes its old entries unused. Only answers are cached, errors are solved again.
+
+use crate::error::AocError;
+use crate::input;
+use crate::solver::{Answer, Part, Solver};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
+use tracing::{debug, warn};
+
+/// the environment variable with the cache directory of the command line and the python module
+pub const ENV: &str = "AOC_CACHE";
+
+lazy_static! {
+    static ref CACHE: RwLock<Option<Cache>> = RwLock::new(None);
+}
+
+/// numbers the temporary files, the threads of `run_all` can insert the same entry at once
+static TEMPORARY: AtomicUsize = AtomicUsize::new(0);
+
+/// caches the answers in `directory` from now on
+pub fn enable(directory: impl Into<PathBuf>) {
+    *CACHE.write().expect("the cache lock is not poisoned") = Some(Cache::new(directory));
+}
+
+pub fn disable() {
+    *CACHE.write().expect("the cache lock is not poisoned") = None;
+}
+
+/// the cache in use, `None` when it is off
+pub fn current() -> Option<Cache> {
+    CACHE
+        .read()
+        .expect("the cache lock is not poisoned")
+        .clone()
+}
+
+/// 64 bit FNV-1a, it is stable between compilers unlike the hasher of std
+pub fn hash(parts: &[&[u8]]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in parts {
+        for byte in part.iter() {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+        // so `["ab", "c"]` and `["a", "bc"]` differ
+        hash ^= 0xff;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+/// one cached answer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: u8,
+    pub part: Part,
+    pub name: String,
+    /// [`Solver::version`] as hex
+    pub version: String,
+    /// [`hash`] of the normalized input as hex
+    pub input: String,
+    pub answer: Answer,
+}
+
+impl Entry {
+    fn file_name(&self) -> String {
+        format!(
+            "day{:02}{}-{}-{}.json",
+            self.day, self.part, self.version, self.input
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cache {
+    directory: PathBuf,
+}
+
+impl Cache {
+    pub fn new(directory: impl Into<PathBuf>) -> Cache {
+        Cache {
+            directory: directory.into(),
+        }
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn entry(solver: &dyn Solver, input: &str, answer: Answer) -> Entry {
+        // the days solve the input normalized, where the newline at the end is optional too
+        let input = input::normalize(input);
+        Entry {
+            day: solver.day(),
+            part: solver.part(),
+            name: solver.name().to_string(),
+            version: format!("{:016x}", solver.version()),
+            input: format!("{:016x}", hash(&[input.trim_end().as_bytes()])),
+            answer,
+        }
+    }
+
+    pub fn get(&self, solver: &dyn Solver, input: &str) -> Option<Answer> {
+        // the answer is not part of the file name
+        let key = Cache::entry(solver, input, Answer::Unsigned(0));
+        let path = self.directory.join(key.file_name());
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == ErrorKind::NotFound => return None,
+            Err(error) => {
+                warn!(path = %path.display(), %error, "could not read the cache");
+                return None;
+            }
+        };
+
+        match serde_json::from_str::<Entry>(&text) {
+            Ok(entry) => {
+                debug!(name = solver.name(), answer = %entry.answer, "cache hit");
+                Some(entry.answer)
+            }
+            Err(error) => {
+                warn!(path = %path.display(), %error, "invalid cache entry");
+                None
+            }
+        }
+    }
+
+    pub fn insert(&self, solver: &dyn Solver, input: &str, answer: Answer) -> Result<(), AocError> {
+        let entry = Cache::entry(solver, input, answer);
+        let path = self.directory.join(entry.file_name());
+        let json = serde_json::to_string(&entry).expect("an entry is valid json");
+
+        // written next to it and renamed, so a reader never sees half an entry
+        let temporary = path.with_extension(format!(
+            "{}-{}.tmp",
+            std::process::id(),
+            TEMPORARY.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&self.directory)?;
+        fs::write(&temporary, json)?;
+        fs::rename(&temporary, &path)?;
+        Ok(())
+    }
+
+    fn files(&self) -> Result<Vec<PathBuf>, AocError> {
+        let read_dir = match fs::read_dir(&self.directory) {
+            Ok(read_dir) => read_dir,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(error.into()),
+        };
+
+        let mut files = Vec::new();
+        for entry in read_dir {
+            let path = entry?.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                files.push(path);
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    /// the cached answers, sorted by their file name. files that are not an entry are skipped
+    pub fn entries(&self) -> Result<Vec<Entry>, AocError> {
+        let mut entries = Vec::new();
+        for path in self.files()? {
+            match serde_json::from_str(&fs::read_to_string(&path)?) {
+                Ok(entry) => entries.push(entry),
+                Err(error) => warn!(path = %path.display(), %error, "invalid cache entry"),
+            }
+        }
+        Ok(entries)
+    }
+
+    /// removes every entry, returns how many there were
+    pub fn clear(&self) -> Result<usize, AocError> {
+        let files = self.files()?;
+        for path in &files {
+            fs::remove_file(path)?;
+        }
+        Ok(files.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver;
+
+    fn cache(name: &str) -> Cache {
+        let directory =
+            std::env::temp_dir().join(format!("aoc2023-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        Cache::new(directory)
+    }
+
+    #[test]
+    fn hash_parts() {
+        assert_eq!(hash(&[b"ab", b"c"]), hash(&[b"ab", b"c"]));
+        assert_ne!(hash(&[b"ab", b"c"]), hash(&[b"a", b"bc"]));
+    }
+
+    #[test]
+    fn insert_and_get() {
+        let cache = cache("insert");
+        let solver = solver::find(9, Part::A).unwrap();
+        assert_eq!(None, cache.get(solver, "1 2 3"));
+
+        cache.insert(solver, "1 2 3", Answer::Signed(4)).unwrap();
+        assert_eq!(Some(Answer::Signed(4)), cache.get(solver, "1 2 3"));
+        assert_eq!(None, cache.get(solver, "1 2 4"));
+        assert_eq!(Some(Answer::Signed(4)), cache.get(solver, "1 2 3 \r\n\n"));
+        assert_eq!(None, cache.get(solver::find(9, Part::B).unwrap(), "1 2 3"));
+
+        let entries = cache.entries().unwrap();
+        assert_eq!(1, entries.len());
+        assert_eq!("day09a", entries[0].name);
+
+        assert_eq!(1, cache.clear().unwrap());
+        assert_eq!(None, cache.get(solver, "1 2 3"));
+        fs::remove_dir_all(cache.directory()).unwrap();
+    }
+
+    #[test]
+    fn off_by_default() {
+        assert_eq!(None, current());
+    }
+
+    #[test]
+    fn insert_from_threads() {
+        let cache = cache("threads");
+        let solver = solver::find(9, Part::A).unwrap();
+
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for _ in 0..20 {
+                        cache.insert(solver, "1 2 3", Answer::Signed(4)).unwrap();
+                    }
+                });
+            }
+        });
+
+        assert_eq!(Some(Answer::Signed(4)), cache.get(solver, "1 2 3"));
+        assert_eq!(1, fs::read_dir(cache.directory()).unwrap().count());
+        fs::remove_dir_all(cache.directory()).unwrap();
+    }
+
+    #[test]
+    fn missing_directory() {
+        let cache = cache("missing");
+        assert_eq!(Vec::<Entry>::new(), cache.entries().unwrap());
+        assert_eq!(0, cache.clear().unwrap());
+    }
+}
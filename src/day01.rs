@@ -1,7 +1,10 @@
-use pyo3::exceptions::PyValueError;
-use pyo3::prelude::*;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::error::{checked, parse_number, AocError};
+use crate::explain::Explanation;
+use crate::input;
+use serde::Serialize;
+use serde_json::json;
+
+const DAY: u8 = 1;
 
 const DIGITS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
 
@@ -9,72 +12,168 @@ const DIGIT_WORDS: [&str; 9] = [
     "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
-#[pyfunction]
-pub fn day01a(file_path: &str) -> PyResult<usize> {
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
+/// the calibration document, line by line
+#[derive(Debug, Serialize)]
+pub struct Puzzle {
+    calibrations: Vec<Calibration>,
+}
 
-    let mut sum = 0;
+#[derive(Debug, Serialize)]
+struct Calibration {
+    text: String,
+    /// the digits of part one
+    digits: Vec<usize>,
+    /// the digits of part two, spelled out digits included
+    digits_and_words: Vec<usize>,
+}
 
-    for line in reader.lines() {
-        let all_chars: Vec<_> = line?.chars().filter(|ch| ch.is_numeric()).collect();
-        if all_chars.is_empty() {
-            return Err(PyErr::new::<PyValueError, _>("not a valid line"));
-        }
-        let first_char = all_chars.first().expect("checked that list is not empty");
-        let last_char = all_chars.last().expect("checked that list is not empty");
-        let formatted_number = format!("{}{}", first_char, last_char);
-        let number = formatted_number.parse::<usize>()?;
-        sum += number;
+pub fn day01_parse(input: &str) -> Result<Puzzle, AocError> {
+    let input = &input::normalize(input);
+    let calibrations = input
+        .lines()
+        .map(|line| {
+            let mut digits_and_words = Vec::new();
+            scan(line, &mut digits_and_words);
+            Calibration {
+                text: line.to_string(),
+                digits: line
+                    .chars()
+                    .filter_map(|ch| ch.to_digit(10))
+                    .map(|digit| digit as usize)
+                    .collect(),
+                digits_and_words,
+            }
+        })
+        .collect();
+
+    Ok(Puzzle { calibrations })
+}
+
+/// the digits of `line` and the number of the first and the last one
+fn calibrate_digits(line: &str, line_number: usize) -> Result<(Vec<char>, usize), AocError> {
+    let all_chars: Vec<_> = line.chars().filter(|ch| ch.is_numeric()).collect();
+    if all_chars.is_empty() {
+        return Err(AocError::parse(DAY, line, line, "a digit").at_line(line_number));
+    }
+    let first_char = all_chars.first().expect("checked that list is not empty");
+    let last_char = all_chars.last().expect("checked that list is not empty");
+    let formatted_number = format!("{}{}", first_char, last_char);
+    let number: usize =
+        parse_number(DAY, line, &formatted_number).map_err(|error| error.at_line(line_number))?;
+
+    Ok((all_chars, number))
+}
+
+pub fn day01a(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    let mut sum: usize = 0;
+
+    for (line, line_number) in input.lines().zip(1..) {
+        let (_, number) = calibrate_digits(line, line_number)?;
+        sum = checked(DAY, sum.checked_add(number))?;
     }
 
     Ok(sum)
 }
 
+pub fn day01a_explain(input: &str) -> Result<Vec<Explanation>, AocError> {
+    let input = &input::normalize(input);
+    input
+        .lines()
+        .zip(1..)
+        .map(|(line, line_number)| {
+            let (digits, number) = calibrate_digits(line, line_number)?;
+            let details = json!({"first": digits.first(), "last": digits.last()});
+            Ok(Explanation::new(line_number, line, &digits, number, details))
+        })
+        .collect()
+}
+
+/// pushes the digits and digit words of `line` in order, words may overlap like in "twone"
 fn scan(line: &str, collected: &mut Vec<usize>) {
-    if line.is_empty() {
-        return;
-    }
+    let mut rest = line;
 
-    for (index, digit) in DIGIT_WORDS.iter().enumerate() {
-        if line.starts_with(digit) {
+    while !rest.is_empty() {
+        if let Some(index) = DIGIT_WORDS.iter().position(|word| rest.starts_with(word)) {
             collected.push(index + 1);
-            let (_, rest) = line.split_at(1);
-            return scan(rest, collected);
+        } else if let Some(index) = DIGITS.iter().position(|digit| rest.starts_with(digit)) {
+            collected.push(index);
         }
+
+        let mut chars = rest.chars();
+        chars.next();
+        rest = chars.as_str();
     }
+}
 
-    for (index, digit) in DIGITS.iter().enumerate() {
-        if let Some(rest) = line.strip_prefix(digit) {
-            collected.push(index);
-            return scan(rest, collected);
-        }
+/// like [`calibrate_digits`], with the digits that are spelled out
+fn calibrate_words(line: &str, line_number: usize) -> Result<(Vec<usize>, usize), AocError> {
+    let mut collected = Vec::new();
+    scan(line, &mut collected);
+
+    if collected.is_empty() {
+        return Err(
+            AocError::parse(DAY, line, line, "a digit or a digit word").at_line(line_number)
+        );
+    }
+    let first_char = collected.first().expect("checked that list is not empty");
+    let last_char = collected.last().expect("checked that list is not empty");
+    let formatted_number = format!("{}{}", first_char, last_char);
+    let number: usize =
+        parse_number(DAY, line, &formatted_number).map_err(|error| error.at_line(line_number))?;
+
+    Ok((collected, number))
+}
+
+pub fn day01b(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    let mut sum: usize = 0;
+
+    for (line, line_number) in input.lines().zip(1..) {
+        let (_, number) = calibrate_words(line, line_number)?;
+        sum = checked(DAY, sum.checked_add(number))?;
     }
 
-    let (_, rest) = line.split_at(1);
-    return scan(rest, collected);
+    Ok(sum)
 }
 
-#[pyfunction]
-pub fn day01b(file_path: &str) -> PyResult<usize> {
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
+pub fn day01b_explain(input: &str) -> Result<Vec<Explanation>, AocError> {
+    let input = &input::normalize(input);
+    input
+        .lines()
+        .zip(1..)
+        .map(|(line, line_number)| {
+            let (digits, number) = calibrate_words(line, line_number)?;
+            let details = json!({"first": digits.first(), "last": digits.last()});
+            Ok(Explanation::new(line_number, line, &digits, number, details))
+        })
+        .collect()
+}
 
-    let mut sum = 0;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    for line in reader.lines() {
+    #[test]
+    fn scan_overlapping_words() {
         let mut collected = Vec::new();
-        scan(&line?, &mut collected);
+        scan("xtwone3four", &mut collected);
+        assert_eq!(vec![2, 1, 3, 4], collected);
+    }
 
-        if collected.is_empty() {
-            return Err(PyErr::new::<PyValueError, _>("not a valid line"));
-        }
-        let first_char = collected.first().expect("checked that list is not empty");
-        let last_char = collected.last().expect("checked that list is not empty");
-        let formatted_number = format!("{}{}", first_char, last_char);
-        let number = formatted_number.parse::<usize>()?;
-        sum += number;
+    #[test]
+    fn scan_multibyte_chars() {
+        let mut collected = Vec::new();
+        scan("tw\u{219}one9", &mut collected);
+        assert_eq!(vec![1, 9], collected);
     }
 
-    Ok(sum)
+    #[test]
+    fn day01a_line_without_digit() {
+        let error = day01a("1abc2\npqrstu\n").unwrap_err();
+        assert_eq!(
+            AocError::parse(DAY, "pqrstu", "pqrstu", "a digit").at_line(2),
+            error
+        );
+    }
 }
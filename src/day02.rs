@@ -1,18 +1,25 @@
-use pyo3::exceptions::PyValueError;
-use pyo3::prelude::*;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::error::{checked, AocError};
+use crate::explain::Explanation;
+use crate::input;
+use crate::parse::{self, Parser};
+use serde::Serialize;
+use serde_json::json;
 use std::str::FromStr;
 
+const DAY: u8 = 2;
+
+pub type HandTuple = (usize, usize, usize);
+
 const MAXIMUM_HAND: Hand = Hand {
     red: 12,
     green: 13,
     blue: 14,
 };
 
-struct Game {
-    id: usize,
-    hands: Vec<Hand>,
+#[derive(Debug, Serialize)]
+pub struct Game {
+    pub id: usize,
+    pub hands: Vec<Hand>,
 }
 
 impl Game {
@@ -35,41 +42,33 @@ impl Game {
 
         max_hand
     }
-}
 
-fn parse_hands(input: &str) -> Result<Vec<Hand>, PyErr> {
-    let mut hands = Vec::new();
-    for hand_text in input.split("; ") {
-        let hand = hand_text.parse()?;
-        hands.push(hand);
+    fn parse(parser: &mut Parser<'_>) -> Result<Game, AocError> {
+        parser.tag("Game")?;
+        let id = parser.number()?;
+        parser.tag(":")?;
+        let hands = parser.separated(";", Hand::parse)?;
+
+        Ok(Game { id, hands })
     }
-    Ok(hands)
 }
 
 impl FromStr for Game {
-    type Err = PyErr;
+    type Err = AocError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let (_, rest) = input
-            .split_once("Game ")
-            .ok_or_else(|| PyErr::new::<PyValueError, _>("not a valid line"))?;
-
-        let (id_string, rest) = rest
-            .split_once(": ")
-            .ok_or_else(|| PyErr::new::<PyValueError, _>("not a valid line"))?;
-
-        let id = id_string.parse()?;
-        let hands = parse_hands(rest)?;
-
-        Ok(Game { id, hands })
+        let mut parser = Parser::new(DAY, input);
+        let game = Game::parse(&mut parser)?;
+        parser.end()?;
+        Ok(game)
     }
 }
 
-#[derive(Debug, Default, PartialEq)]
-struct Hand {
-    red: usize,
-    green: usize,
-    blue: usize,
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct Hand {
+    pub red: usize,
+    pub green: usize,
+    pub blue: usize,
 }
 
 impl Hand {
@@ -77,75 +76,111 @@ impl Hand {
         self.red <= maximum.red && self.green <= maximum.green && self.blue <= maximum.blue
     }
 
-    pub fn to_score(&self) -> usize {
-        self.red * self.green * self.blue
+    pub fn to_score(&self) -> Result<usize, AocError> {
+        let score = self.red.checked_mul(self.green);
+        checked(DAY, score.and_then(|score| score.checked_mul(self.blue)))
     }
-}
 
-impl FromStr for Hand {
-    type Err = PyErr;
-
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
+    fn parse(parser: &mut Parser<'_>) -> Result<Hand, AocError> {
         let mut hand = Hand::default();
 
-        for item in input.split(", ") {
-            let (count_text, colour) = item
-                .split_once(' ')
-                .ok_or_else(|| PyErr::new::<PyValueError, _>("not a valid item"))?;
-            let count = count_text.parse()?;
-            match colour {
+        parser.separated(",", |parser| {
+            let count = parser.number()?;
+            match parser.word()? {
                 "red" => hand.red = count,
                 "blue" => hand.blue = count,
                 "green" => hand.green = count,
-                _ => return Err(PyErr::new::<PyValueError, _>("invalid colour")),
+                colour => return Err(parser.error_at(colour, "'red', 'green' or 'blue'")),
             }
-        }
+            Ok(())
+        })?;
 
         Ok(hand)
     }
 }
 
-#[pyfunction]
-pub fn day02a(file_path: &str) -> PyResult<usize> {
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
+impl FromStr for Hand {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(DAY, input);
+        let hand = Hand::parse(&mut parser)?;
+        parser.end()?;
+        Ok(hand)
+    }
+}
 
-    let mut sum = 0;
+#[derive(Debug, Serialize)]
+pub struct Puzzle {
+    games: Vec<Game>,
+}
 
-    for line in reader.lines() {
-        let game: Game = line?.parse()?;
+pub fn day02_parse(input: &str) -> Result<Puzzle, AocError> {
+    let input = &input::normalize(input);
+    Ok(Puzzle {
+        games: parse::lines(DAY, input, Game::parse)?,
+    })
+}
+
+pub fn day02a(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    let mut sum: usize = 0;
+
+    for game in parse::lines(DAY, input, Game::parse)? {
         if game.get_max().allowed(&MAXIMUM_HAND) {
-            sum += game.id;
+            sum = checked(DAY, sum.checked_add(game.id))?;
         }
     }
 
     Ok(sum)
 }
 
-#[pyfunction]
-pub fn day02b(file_path: &str) -> PyResult<usize> {
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
+pub fn day02b(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    let mut sum: usize = 0;
 
-    let mut sum = 0;
-
-    for line in reader.lines() {
-        let game: Game = line?.parse()?;
-        sum += game.get_max().to_score()
+    for game in parse::lines(DAY, input, Game::parse)? {
+        sum = checked(DAY, sum.checked_add(game.get_max().to_score()?))?;
     }
 
     Ok(sum)
 }
 
-#[pyfunction]
-pub fn day02_parse_hand(input: &str) -> PyResult<(usize, usize, usize)> {
-    let hand: Hand = input.parse()?;
+pub fn day02a_explain(input: &str) -> Result<Vec<Explanation>, AocError> {
+    let input = &input::normalize(input);
+    parse::numbered_lines(input)
+        .map(|(line, line_number)| {
+            let game = parse::line(DAY, line, line_number, Game::parse)?;
+            let max = game.get_max();
+            let allowed = max.allowed(&MAXIMUM_HAND);
+            let contribution = if allowed { game.id } else { 0 };
+            let details = json!({"max": max, "allowed": allowed});
+            Ok(Explanation::new(line_number, line, &game, contribution, details))
+        })
+        .collect()
+}
+
+pub fn day02b_explain(input: &str) -> Result<Vec<Explanation>, AocError> {
+    let input = &input::normalize(input);
+    parse::numbered_lines(input)
+        .map(|(line, line_number)| {
+            let game = parse::line(DAY, line, line_number, Game::parse)?;
+            let max = game.get_max();
+            let power = max.to_score()?;
+            Ok(Explanation::new(line_number, line, &game, power, json!({"max": max})))
+        })
+        .collect()
+}
+
+pub fn day02_parse_hand(input: &str) -> Result<HandTuple, AocError> {
+    let input = &input::normalize(input);
+    let hand: Hand = input.parse().map_err(|error: AocError| error.at_line(1))?;
     Ok((hand.red, hand.green, hand.blue))
 }
 
-#[pyfunction]
-pub fn day02_parse_game(input: &str) -> PyResult<(usize, Vec<(usize, usize, usize)>)> {
-    let game: Game = input.parse()?;
+pub fn day02_parse_game(input: &str) -> Result<(usize, Vec<HandTuple>), AocError> {
+    let input = &input::normalize(input);
+    let game: Game = input.parse().map_err(|error: AocError| error.at_line(1))?;
 
     Ok((
         game.id,
@@ -155,3 +190,62 @@ pub fn day02_parse_game(input: &str) -> PyResult<(usize, Vec<(usize, usize, usiz
             .collect(),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hand_from_str() {
+        let hand: Hand = "1 green, 2 red, 3 blue".parse().unwrap();
+        assert_eq!(
+            Hand {
+                red: 2,
+                green: 1,
+                blue: 3
+            },
+            hand
+        );
+    }
+
+    #[test]
+    fn hand_from_str_unknown_colour() {
+        let error = "1 green, 2 rad".parse::<Hand>().unwrap_err();
+        assert!(matches!(error, AocError::Parse { column: 12, .. }));
+    }
+
+    #[test]
+    fn game_from_str() {
+        let game: Game = "Game 4: 1 green, 3 red; 3 green".parse().unwrap();
+        assert_eq!(4, game.id);
+        assert_eq!(2, game.hands.len());
+        assert_eq!(
+            Hand {
+                red: 3,
+                green: 3,
+                blue: 0
+            },
+            game.get_max()
+        );
+    }
+
+    #[test]
+    fn game_from_str_extra_whitespace() {
+        let game: Game = "Game  4:  1 green,3 red ;3 green\r".parse().unwrap();
+        assert_eq!(4, game.id);
+        assert_eq!(2, game.hands.len());
+    }
+
+    #[test]
+    fn game_from_str_column_of_second_hand() {
+        let error = day02_parse_game("Game 4: 1 green, 3 rad; 3 green").unwrap_err();
+        assert!(matches!(
+            error,
+            AocError::Parse {
+                line: 1,
+                column: 20,
+                ..
+            }
+        ));
+    }
+}
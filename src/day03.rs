@@ -1,92 +1,39 @@
+use crate::error::{checked, checked_sum, AocError};
+use crate::grid::{Grid, Position};
+use crate::input;
+use crate::render::{Mark, Picture};
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
+use serde::ser::{Error, SerializeStruct};
+use serde::{Serialize, Serializer};
 use std::collections::HashMap;
 use std::str::FromStr;
 
-type InnerTokens = HashMap<(usize, usize), SchemaToken>;
-
-#[derive(Debug, Default)]
-struct Locations {
-    location: (usize, usize),
-    diagonal_left_up: bool,
-    diagonal_right_up: bool,
-    diagonal_left_down: bool,
-    diagonal_right_down: bool,
-    up: bool,
-    down: bool,
-    left: bool,
-    right: bool,
+const DAY: u8 = 3;
+
+/// a number in the schematic, `from` and `to` are the x of its first and last digit
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct PartNumber {
+    value: usize,
+    y: usize,
+    from: usize,
+    to: usize,
 }
 
-impl Locations {
-    pub fn new(x: usize, y: usize) -> Locations {
-        Locations {
-            location: (x, y),
-            ..Default::default()
-        }
+impl PartNumber {
+    fn touches(&self, (x, y): Position) -> bool {
+        self.y.abs_diff(y) <= 1 && self.from <= x + 1 && x <= self.to + 1
     }
+}
 
-    pub fn resolve(&mut self, tokens: &InnerTokens) {
-        let (gear_x, gear_y) = self.location;
-
-        // check diagonals
-        self.diagonal_left_up = gear_x
-            .checked_sub(1)
-            .and_then(|valid_x| gear_y.checked_sub(1).map(|valid_y| (valid_x, valid_y)))
-            .and_then(|(valid_x, valid_y)| tokens.get(&(valid_x, valid_y)))
-            .map(|token| token.is_number())
-            .unwrap_or(false);
-
-        self.diagonal_right_up = gear_x
-            .checked_add(1)
-            .and_then(|valid_x| gear_y.checked_sub(1).map(|valid_y| (valid_x, valid_y)))
-            .and_then(|(valid_x, valid_y)| tokens.get(&(valid_x, valid_y)))
-            .map(|token| token.is_number())
-            .unwrap_or(false);
-
-        self.diagonal_left_down = gear_x
-            .checked_sub(1)
-            .and_then(|valid_x| gear_y.checked_add(1).map(|valid_y| (valid_x, valid_y)))
-            .and_then(|(valid_x, valid_y)| tokens.get(&(valid_x, valid_y)))
-            .map(|token| token.is_number())
-            .unwrap_or(false);
-
-        self.diagonal_right_down = gear_x
-            .checked_add(1)
-            .and_then(|valid_x| gear_y.checked_add(1).map(|valid_y| (valid_x, valid_y)))
-            .and_then(|(valid_x, valid_y)| tokens.get(&(valid_x, valid_y)))
-            .map(|token| token.is_number())
-            .unwrap_or(false);
-
-        // check adjacent
-
-        self.up = gear_y
-            .checked_sub(1)
-            .map(|valid_y| (gear_x, valid_y))
-            .and_then(|(valid_x, valid_y)| tokens.get(&(valid_x, valid_y)))
-            .map(|token| token.is_number())
-            .unwrap_or(false);
-
-        self.down = gear_y
-            .checked_add(1)
-            .map(|valid_y| (gear_x, valid_y))
-            .and_then(|(valid_x, valid_y)| tokens.get(&(valid_x, valid_y)))
-            .map(|token| token.is_number())
-            .unwrap_or(false);
-
-        self.left = gear_x
-            .checked_sub(1)
-            .map(|valid_x| (valid_x, gear_y))
-            .and_then(|(valid_x, valid_y)| tokens.get(&(valid_x, valid_y)))
-            .map(|token| token.is_number())
-            .unwrap_or(false);
-
-        self.right = gear_x
-            .checked_add(1)
-            .map(|valid_x| (valid_x, gear_y))
-            .and_then(|(valid_x, valid_y)| tokens.get(&(valid_x, valid_y)))
-            .map(|token| token.is_number())
-            .unwrap_or(false);
-    }
+fn parse_number_at(text: &str, x: usize, y: usize) -> Result<usize, AocError> {
+    text.parse().map_err(|_| AocError::Parse {
+        day: DAY,
+        line: y + 1,
+        column: x + 1,
+        text: text.to_string(),
+        expected: String::from("a number"),
+    })
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -98,25 +45,15 @@ pub enum SchemaToken {
 
 impl SchemaToken {
     pub fn is_symbol(&self) -> bool {
-        match self {
-            SchemaToken::Symbol => true,
-            SchemaToken::Gear => true,
-            _ => false,
-        }
+        matches!(self, SchemaToken::Symbol | SchemaToken::Gear)
     }
 
     pub fn is_number(&self) -> bool {
-        match self {
-            SchemaToken::Number(_) => true,
-            _ => false,
-        }
+        matches!(self, SchemaToken::Number(_))
     }
 
     pub fn is_gear(&self) -> bool {
-        match self {
-            SchemaToken::Gear => true,
-            _ => false,
-        }
+        matches!(self, SchemaToken::Gear)
     }
 
     pub fn as_number(&self) -> Option<char> {
@@ -136,271 +73,116 @@ impl SchemaToken {
     }
 }
 
-impl IntoPy<Py<PyAny>> for SchemaToken {
-    fn into_py(self, py: Python<'_>) -> Py<PyAny> {
+/// the character it was parsed from, a number is its digit
+impl Serialize for SchemaToken {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match self {
-            Self::Number(number) => number.into_py(py),
-            Self::Gear => String::from("*").into_py(py),
-            Self::Symbol => String::from("#").into_py(py),
+            SchemaToken::Number(digit) => serializer.serialize_char(*digit),
+            SchemaToken::Gear => serializer.serialize_char('*'),
+            SchemaToken::Symbol => serializer.serialize_char('#'),
         }
     }
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[derive(Debug)]
 pub struct Tokenizer {
-    #[pyo3(get)]
-    tokens: HashMap<(usize, usize), SchemaToken>,
-    size: (usize, usize),
+    grid: Grid<Option<SchemaToken>>,
 }
 
 impl Tokenizer {
-    pub fn sum_valid_numbers(&self) -> PyResult<usize> {
-        let mut current_number_text = String::new();
-        let mut total = 0;
-
-        for (y, x) in (0..=self.size.1).flat_map(|a| (0..self.size.0).map(move |b| (a, b))) {
-            let item = if let Some(item) = self.tokens.get(&(x, y)) {
-                item
-            } else {
-                continue;
-            };
-
-            if let SchemaToken::Number(ch) = item {
-                current_number_text.push(*ch);
-            }
+    /// the non empty cells by their position
+    pub fn tokens(&self) -> HashMap<Position, SchemaToken> {
+        self.grid
+            .iter()
+            .filter_map(|(position, token)| token.clone().map(|token| (position, token)))
+            .collect()
+    }
 
-            let next_item = self.tokens.get(&(x + 1, y));
+    /// the digits as their value, empty cells -1, symbols -2 and gears -3
+    pub fn codes(&self) -> Grid<i8> {
+        self.grid.map(|token| match token {
+            None => -1,
+            Some(SchemaToken::Symbol) => -2,
+            Some(SchemaToken::Gear) => -3,
+            Some(SchemaToken::Number(digit)) => digit
+                .to_digit(10)
+                .and_then(|digit| i8::try_from(digit).ok())
+                .expect("a number token is a digit"),
+        })
+    }
 
-            // is number and next is empty or symbol
-            if item.is_number() && (next_item.is_none() || next_item.unwrap().is_symbol()) {
-                if self.has_symbol_around(x - (current_number_text.len() - 1), x, y)? {
-                    let number: usize = current_number_text.parse()?;
-                    total += number;
-                }
+    fn is_symbol(&self, position: Position) -> bool {
+        matches!(self.grid.get(position), Some(Some(token)) if token.is_symbol())
+    }
 
-                current_number_text = String::new();
+    fn numbers(&self) -> Result<Vec<PartNumber>, AocError> {
+        let mut numbers = Vec::new();
+
+        for (y, row) in self.grid.rows().enumerate() {
+            let mut digits = String::new();
+            // one past the end, so the number at the end of the row is finished as well
+            for x in 0..=row.len() {
+                match row.get(x).and_then(|token| token.as_ref()?.as_number()) {
+                    Some(digit) => digits.push(digit),
+                    None if !digits.is_empty() => {
+                        let from = x - digits.len();
+                        numbers.push(PartNumber {
+                            value: parse_number_at(&digits, from, y)?,
+                            y,
+                            from,
+                            to: x - 1,
+                        });
+                        digits.clear();
+                    }
+                    None => (),
+                }
             }
         }
 
-        Ok(total)
+        Ok(numbers)
     }
 
-    pub fn has_symbol_around(&self, from: usize, to: usize, y: usize) -> PyResult<bool> {
-        for (area_y, area_x) in (y.saturating_sub(1)..=y.saturating_add(1))
-            .flat_map(|a| (from.saturating_sub(1)..=to.saturating_add(1)).map(move |b| (a, b)))
-        {
-            if let Some(true) = self.tokens.get(&(area_x, area_y)).map(|x| x.is_symbol()) {
-                return Ok(true);
+    pub fn sum_valid_numbers(&self) -> Result<usize, AocError> {
+        let mut total: usize = 0;
+
+        for number in self.numbers()? {
+            if self.has_symbol_around(number.from, number.to, number.y) {
+                total = checked(DAY, total.checked_add(number.value))?;
             }
         }
 
-        Ok(false)
+        Ok(total)
     }
 
-    pub fn gather_gear_locations(&self) -> PyResult<Vec<(usize, usize)>> {
-        let gears = self
-            .tokens
-            .iter()
-            .flat_map(|(location, value)| {
-                if value.is_gear() {
-                    Some(*location)
-                } else {
-                    None
-                }
-            })
-            .collect();
+    pub fn has_symbol_around(&self, from: usize, to: usize, y: usize) -> bool {
+        (from..=to)
+            .flat_map(|x| self.grid.neighbours8((x, y)))
+            .any(|position| self.is_symbol(position))
+    }
 
-        Ok(gears)
+    pub fn gather_gear_locations(&self) -> Vec<Position> {
+        self.grid
+            .iter()
+            .filter(|(_, token)| matches!(token, Some(token) if token.is_gear()))
+            .map(|(position, _)| position)
+            .collect()
     }
 
-    pub fn find_valid_gear_ratios(&self, gears: &[(usize, usize)]) -> PyResult<Vec<usize>> {
+    /// the ratios of the gears that touch exactly two numbers
+    pub fn find_valid_gear_ratios(&self, gears: &[Position]) -> Result<Vec<usize>, AocError> {
+        let numbers = self.numbers()?;
         let mut ratios = Vec::new();
 
-        for (gear_x, gear_y) in gears {
-            let mut locations = Locations::new(*gear_x, *gear_y);
-            locations.resolve(&self.tokens);
-            let mut numbers: Vec<usize> = Vec::new();
-
-            if locations.diagonal_left_up && locations.up == false {
-                let mut reverse_string = String::new();
-                for x_pointer in (0..*gear_x).rev() {
-                    if let Some(token) = self.tokens.get(&(x_pointer, gear_y - 1)) {
-                        token.as_number().map(|x| reverse_string.push(x));
-                    } else {
-                        break;
-                    }
-                }
-                let string: String = reverse_string.chars().rev().collect();
-                numbers.push(string.parse()?);
-            }
-            if locations.diagonal_left_down && locations.down == false {
-                let mut reverse_string = String::new();
-                for x_pointer in (0..*gear_x).rev() {
-                    if let Some(token) = self.tokens.get(&(x_pointer, gear_y + 1)) {
-                        token.as_number().map(|x| reverse_string.push(x));
-                    } else {
-                        break;
-                    }
-                }
-                let string: String = reverse_string.chars().rev().collect();
-                numbers.push(string.parse()?);
-            }
+        for gear in gears {
+            let touching: Vec<usize> = numbers
+                .iter()
+                .filter(|number| number.touches(*gear))
+                .map(|number| number.value)
+                .collect();
 
-            if locations.diagonal_right_up && locations.up == false {
-                let mut string = String::new();
-                for x_pointer in (gear_x + 1)..=self.size.0 {
-                    if let Some(token) = self.tokens.get(&(x_pointer, gear_y - 1)) {
-                        token.as_number().map(|x| string.push(x));
-                    } else {
-                        break;
-                    }
-                }
-                numbers.push(string.parse()?);
-            }
-
-            if locations.diagonal_right_down && locations.down == false {
-                let mut string = String::new();
-                for x_pointer in (gear_x + 1)..=self.size.0 {
-                    if let Some(token) = self.tokens.get(&(x_pointer, gear_y + 1)) {
-                        token.as_number().map(|x| string.push(x));
-                    } else {
-                        break;
-                    }
-                }
-                numbers.push(string.parse()?);
-            }
-
-            if locations.left {
-                let mut reverse_string = String::new();
-                for x_pointer in (0..*gear_x).rev() {
-                    if let Some(token) = self.tokens.get(&(x_pointer, *gear_y)) {
-                        token.as_number().map(|x| reverse_string.push(x));
-                    } else {
-                        break;
-                    }
-                }
-                let string: String = reverse_string.chars().rev().collect();
-                numbers.push(string.parse()?);
-            }
-
-            if locations.right {
-                let mut string = String::new();
-                for x_pointer in (gear_x + 1)..=self.size.0 {
-                    if let Some(token) = self.tokens.get(&(x_pointer, *gear_y)) {
-                        token.as_number().map(|x| string.push(x));
-                    } else {
-                        break;
-                    }
-                }
-                numbers.push(string.parse()?);
-            }
-
-            if locations.up {
-                if locations.diagonal_left_up && locations.diagonal_right_up == false {
-                    let mut reverse_string = String::new();
-                    for x_pointer in (0..=*gear_x).rev() {
-                        if let Some(token) = self.tokens.get(&(x_pointer, gear_y - 1)) {
-                            token.as_number().map(|x| reverse_string.push(x));
-                        } else {
-                            break;
-                        }
-                    }
-                    let string: String = reverse_string.chars().rev().collect();
-                    numbers.push(string.parse()?);
-                }
-                if locations.diagonal_right_up && locations.diagonal_left_up == false {
-                    let mut string = String::new();
-                    for x_pointer in *gear_x..=self.size.0 {
-                        if let Some(token) = self.tokens.get(&(x_pointer, gear_y - 1)) {
-                            token.as_number().map(|x| string.push(x));
-                        } else {
-                            break;
-                        }
-                    }
-                    numbers.push(string.parse()?);
-                }
-                if locations.diagonal_left_up == false && locations.diagonal_right_up == false {
-                    let mut string = String::new();
-                    self.tokens
-                        .get(&(*gear_x, gear_y - 1))
-                        .and_then(SchemaToken::as_number)
-                        .map(|x| string.push(x));
-                    numbers.push(string.parse()?);
-                }
-                // can we assume that a number is a maximum of length 3 ?
-                if locations.diagonal_left_up && locations.up && locations.diagonal_right_up {
-                    let mut string = String::new();
-                    self.tokens
-                        .get(&(*gear_x - 1, gear_y - 1))
-                        .and_then(SchemaToken::as_number)
-                        .map(|x| string.push(x));
-                    self.tokens
-                        .get(&(*gear_x, gear_y - 1))
-                        .and_then(SchemaToken::as_number)
-                        .map(|x| string.push(x));
-                    self.tokens
-                        .get(&(*gear_x + 1, gear_y - 1))
-                        .and_then(SchemaToken::as_number)
-                        .map(|x| string.push(x));
-                    numbers.push(string.parse()?);
-                }
-            }
-
-            if locations.down {
-                if locations.diagonal_left_down && locations.diagonal_right_down == false {
-                    let mut reverse_string = String::new();
-                    for x_pointer in (0..=*gear_x).rev() {
-                        if let Some(token) = self.tokens.get(&(x_pointer, gear_y + 1)) {
-                            token.as_number().map(|x| reverse_string.push(x));
-                        } else {
-                            break;
-                        }
-                    }
-                    let string: String = reverse_string.chars().rev().collect();
-                    numbers.push(string.parse()?);
-                }
-
-                if locations.diagonal_right_down && locations.diagonal_left_down == false {
-                    let mut string = String::new();
-                    for x_pointer in *gear_x..=self.size.0 {
-                        if let Some(token) = self.tokens.get(&(x_pointer, gear_y + 1)) {
-                            token.as_number().map(|x| string.push(x));
-                        } else {
-                            break;
-                        }
-                    }
-                    numbers.push(string.parse()?);
-                }
-                if locations.diagonal_left_down == false && locations.diagonal_right_down == false {
-                    let mut string = String::new();
-                    self.tokens
-                        .get(&(*gear_x, gear_y + 1))
-                        .and_then(SchemaToken::as_number)
-                        .map(|x| string.push(x));
-                    numbers.push(string.parse()?);
-                }
-                // can we assume that a number is a maximum of length 3 ?
-                if locations.diagonal_left_down && locations.down && locations.diagonal_right_down {
-                    let mut string = String::new();
-                    self.tokens
-                        .get(&(*gear_x - 1, gear_y + 1))
-                        .and_then(SchemaToken::as_number)
-                        .map(|x| string.push(x));
-                    self.tokens
-                        .get(&(*gear_x, gear_y + 1))
-                        .and_then(SchemaToken::as_number)
-                        .map(|x| string.push(x));
-                    self.tokens
-                        .get(&(*gear_x + 1, gear_y + 1))
-                        .and_then(SchemaToken::as_number)
-                        .map(|x| string.push(x));
-                    numbers.push(string.parse()?);
-                }
-            }
-
-            if numbers.len() == 2 {
-                ratios.push(numbers[0] * numbers[1]);
+            if let [first, second] = touching[..] {
+                ratios.push(checked(DAY, first.checked_mul(second))?);
             }
         }
 
@@ -408,54 +190,135 @@ impl Tokenizer {
     }
 }
 
+/// the `grid` of tokens and the `numbers` in it
+impl Serialize for Tokenizer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let numbers = self.numbers().map_err(S::Error::custom)?;
+        let mut state = serializer.serialize_struct("Tokenizer", 2)?;
+        state.serialize_field("grid", &self.grid)?;
+        state.serialize_field("numbers", &numbers)?;
+        state.end()
+    }
+}
+
 impl FromStr for Tokenizer {
-    type Err = PyErr;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut tokens = HashMap::new();
-        let mut max_x = 0;
-        let mut max_y = 0;
-
-        for (y, line) in s.lines().enumerate() {
-            for (x, ch) in line.chars().enumerate() {
-                if let Some(item) = SchemaToken::from_char(ch) {
-                    tokens.insert((x, y), item);
-                }
-                max_x = x;
-            }
-            max_y = y;
-        }
+        let grid: Grid<char> = s.parse()?;
 
         Ok(Tokenizer {
-            tokens,
-            size: (max_x + 1, max_y + 1),
+            grid: grid.map(|ch| SchemaToken::from_char(*ch)),
         })
     }
 }
 
-#[pyfunction]
-pub fn day03a(file_path: &str) -> PyResult<usize> {
-    let data = std::fs::read_to_string(file_path)?;
-    let data = data.trim();
+pub fn day03_parse(input: &str) -> Result<Tokenizer, AocError> {
+    let input = &input::normalize(input);
+    let tokenizer: Tokenizer = input.parse()?;
+    // the numbers are parsed while serializing, so their errors are found here already
+    tokenizer.numbers()?;
+    Ok(tokenizer)
+}
 
-    let tokenizer: Tokenizer = data.parse()?;
+pub fn day03a(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    let tokenizer: Tokenizer = input.parse()?;
 
     tokenizer.sum_valid_numbers()
 }
 
-#[pyfunction]
-pub fn day03b(file_path: &str) -> PyResult<usize> {
-    let data = std::fs::read_to_string(file_path)?;
-    let data = data.trim();
-
-    let tokenizer: Tokenizer = data.parse()?;
-    let gear_locations = tokenizer.gather_gear_locations()?;
+pub fn day03b(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    let tokenizer: Tokenizer = input.parse()?;
+    let gear_locations = tokenizer.gather_gear_locations();
     let gear_ratios = tokenizer.find_valid_gear_ratios(&gear_locations)?;
 
-    Ok(gear_ratios.into_iter().sum())
+    checked_sum(DAY, gear_ratios)
 }
 
-#[pyfunction]
-pub fn day03a_parse_tokens(schema: &str) -> PyResult<Tokenizer> {
+/// the part numbers, the numbers that are not and the gears that touch exactly two numbers
+pub fn day03_render(input: &str) -> Result<Picture, AocError> {
+    let input = &input::normalize(input);
+    let cells: Grid<char> = input.parse()?;
+    let tokenizer = Tokenizer {
+        grid: cells.map(|ch| SchemaToken::from_char(*ch)),
+    };
+    let numbers = tokenizer.numbers()?;
+    let mut picture = Picture::new(DAY, cells);
+
+    for (position, token) in tokenizer.grid.iter() {
+        if matches!(token, Some(token) if token.is_symbol()) {
+            picture.mark(position, Mark::Anchor);
+        }
+    }
+    for number in &numbers {
+        let mark = if tokenizer.has_symbol_around(number.from, number.to, number.y) {
+            Mark::Counted
+        } else {
+            Mark::Ignored
+        };
+        for x in number.from..=number.to {
+            picture.mark((x, number.y), mark);
+        }
+    }
+    for gear in tokenizer.gather_gear_locations() {
+        if numbers.iter().filter(|number| number.touches(gear)).count() == 2 {
+            picture.mark(gear, Mark::Special);
+        }
+    }
+
+    picture.describe(Mark::Counted, "a part number, it touches a symbol");
+    picture.describe(Mark::Ignored, "a number without a symbol around it");
+    picture.describe(Mark::Anchor, "a symbol");
+    picture.describe(Mark::Special, "a gear, it touches exactly two part numbers");
+    Ok(picture)
+}
+
+pub fn day03a_parse_tokens(schema: &str) -> Result<Tokenizer, AocError> {
+    let schema = &input::normalize(schema);
     schema.parse()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenizer_from_str() {
+        let tokenizer: Tokenizer = "12.\n.*#".parse().unwrap();
+        let tokens = tokenizer.tokens();
+        assert_eq!(4, tokens.len());
+
+        assert_eq!(Some(&SchemaToken::Number('1')), tokens.get(&(0, 0)));
+        assert_eq!(Some(&SchemaToken::Number('2')), tokens.get(&(1, 0)));
+        assert_eq!(Some(&SchemaToken::Gear), tokens.get(&(1, 1)));
+        assert_eq!(Some(&SchemaToken::Symbol), tokens.get(&(2, 1)));
+        assert_eq!(None, tokens.get(&(2, 0)));
+    }
+
+    #[test]
+    fn numbers() {
+        let tokenizer: Tokenizer = "12.3\n.45.".parse().unwrap();
+        let numbers: Vec<(usize, usize, usize)> = tokenizer
+            .numbers()
+            .unwrap()
+            .into_iter()
+            .map(|number| (number.value, number.from, number.to))
+            .collect();
+        assert_eq!(vec![(12, 0, 1), (3, 3, 3), (45, 1, 2)], numbers);
+    }
+
+    #[test]
+    fn gear_ratio_needs_two_numbers() {
+        assert_eq!(Ok(6), day03b("2..\n.*3"));
+        assert_eq!(Ok(0), day03b("2..\n.*."));
+        assert_eq!(Ok(0), day03b("2.1\n.*3"));
+    }
+
+    #[test]
+    fn number_next_to_symbol() {
+        assert_eq!(Ok(12), day03a("12.\n..#"));
+        assert_eq!(Ok(0), day03a("12.\n...\n..#"));
+    }
+}
@@ -1,8 +1,12 @@
-use pyo3::exceptions::PyValueError;
-use pyo3::prelude::*;
+use crate::error::{checked, checked_sum, AocError};
+use crate::explain::Explanation;
+use crate::input;
+use crate::parse::{self, Parser};
+use serde::Serialize;
+use serde_json::json;
 use std::collections::{BTreeMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+
+const DAY: u8 = 4;
 
 #[derive(Debug, Default)]
 struct Counter {
@@ -10,12 +14,13 @@ struct Counter {
 }
 
 impl Counter {
-    fn add(&mut self, number: usize, amount: usize) {
+    fn add(&mut self, number: usize, amount: usize) -> Result<(), AocError> {
         let entry = self.table.entry(number).or_default();
-        *entry += amount;
+        *entry = checked(DAY, entry.checked_add(amount))?;
+        Ok(())
     }
 
-    fn add_one(&mut self, number: usize) {
+    fn add_one(&mut self, number: usize) -> Result<(), AocError> {
         self.add(number, 1)
     }
 
@@ -23,93 +28,179 @@ impl Counter {
         self.table.get(&number)
     }
 
-    fn sum(&self) -> usize {
-        self.table.values().sum()
+    fn sum(&self) -> Result<usize, AocError> {
+        checked_sum(DAY, self.table.values().copied())
     }
 }
 
-fn find_matches(winning: &str, ours: &str) -> PyResult<usize> {
-    let winning_set: HashSet<usize> = winning
-        .split_ascii_whitespace()
-        .map(std::str::FromStr::from_str)
-        .collect::<Result<_, _>>()?;
-
-    let mut matched: usize = 0;
+#[derive(Debug, Serialize)]
+struct Card {
+    number: usize,
+    winning: Vec<usize>,
+    ours: Vec<usize>,
+}
 
-    for text in ours.split_ascii_whitespace() {
-        let number: usize = text.parse()?;
-        if winning_set.contains(&number) {
-            matched += 1;
-        }
+impl Card {
+    fn matches(&self) -> usize {
+        let winning_set: HashSet<usize> = self.winning.iter().copied().collect();
+        self.ours
+            .iter()
+            .filter(|number| winning_set.contains(number))
+            .count()
     }
+}
 
-    Ok(matched)
+fn parse_line(parser: &mut Parser<'_>) -> Result<Card, AocError> {
+    parser.tag("Card")?;
+    let number = parser.number()?;
+    parser.tag(":")?;
+    let winning = parser.numbers()?;
+    parser.tag("|")?;
+    let ours = parser.numbers()?;
+
+    Ok(Card {
+        number,
+        winning,
+        ours,
+    })
 }
 
-fn parse_line(line: &str) -> PyResult<(usize, &str, &str)> {
-    let (round_text, rest) = line
-        .split_once(": ")
-        .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?;
-    let (_, round_number_text) = round_text
-        .split_once(" ")
-        .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?;
-    let round_number: usize = round_number_text.trim().parse()?;
-    let (winning, ours) = rest
-        .split_once(" | ")
-        .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?;
-
-    Ok((round_number, winning, ours))
+fn get_match_score(card: &Card) -> Result<usize, AocError> {
+    match card.matches() {
+        0 => Ok(0),
+        matches => {
+            let exponent = u32::try_from(matches - 1).ok();
+            checked(
+                DAY,
+                exponent.and_then(|exponent| 2usize.checked_pow(exponent)),
+            )
+        }
+    }
 }
 
-fn get_match_score(line: &str) -> PyResult<usize> {
-    let (_, winning, ours) = parse_line(line)?;
+/// adds the card and the copies it wins, returns how many copies of the card there are
+fn add_to_counter(card: &Card, counter: &mut Counter) -> Result<usize, AocError> {
+    let round_number = card.number;
+    let matched = card.matches();
+    let multiplier = checked(
+        DAY,
+        counter
+            .get(round_number)
+            .copied()
+            .unwrap_or(0)
+            .checked_add(1),
+    )?;
+    counter.add_one(round_number)?;
+    checked(DAY, round_number.checked_add(matched))?;
+
+    for extra_card in (1..=matched).map(|offset| round_number + offset) {
+        counter.add(extra_card, multiplier)?;
+    }
 
-    let matched = find_matches(winning, ours)? as u32;
+    Ok(multiplier)
+}
 
-    let score = match matched {
-        0 => 0,
-        1 => 1,
-        other => 2usize.pow(other.saturating_sub(1)),
-    };
+#[derive(Debug, Serialize)]
+pub struct Puzzle {
+    cards: Vec<Card>,
+}
 
-    Ok(score)
+pub fn day04_parse(input: &str) -> Result<Puzzle, AocError> {
+    let input = &input::normalize(input);
+    Ok(Puzzle {
+        cards: parse::lines(DAY, input, parse_line)?,
+    })
 }
 
-fn add_to_counter(line: &str, counter: &mut Counter) -> PyResult<()> {
-    let (round_number, winning, ours) = parse_line(line)?;
-    let matched = find_matches(winning, ours)?;
-    let multiplier = 1 + counter.get(round_number).copied().unwrap_or(0);
-    counter.add_one(round_number);
+pub fn day04a(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    let cards = parse::lines(DAY, input, parse_line)?;
+    let scores = cards
+        .iter()
+        .map(get_match_score)
+        .collect::<Result<Vec<_>, _>>()?;
+    checked_sum(DAY, scores)
+}
 
-    for extra_card in (round_number + 1)..=(round_number + matched) {
-        counter.add(extra_card, multiplier)
+pub fn day04b(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    let mut counter = Counter::default();
+    for card in parse::lines(DAY, input, parse_line)? {
+        add_to_counter(&card, &mut counter)?;
     }
 
-    Ok(())
+    counter.sum()
 }
 
-#[pyfunction]
-pub fn day04a(file_path: &str) -> PyResult<usize> {
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
+pub fn day04a_explain(input: &str) -> Result<Vec<Explanation>, AocError> {
+    let input = &input::normalize(input);
+    parse::numbered_lines(input)
+        .map(|(line, line_number)| {
+            let card = parse::line(DAY, line, line_number, parse_line)?;
+            let score = get_match_score(&card)?;
+            let details = json!({"matches": card.matches()});
+            Ok(Explanation::new(line_number, line, &card, score, details))
+        })
+        .collect()
+}
 
-    let mut scores = 0;
-    for line in reader.lines() {
-        scores += get_match_score(&line?)?;
-    }
+/// a card adds itself and the copies it wins, the copies of the card times its matches
+pub fn day04b_explain(input: &str) -> Result<Vec<Explanation>, AocError> {
+    let input = &input::normalize(input);
+    let mut counter = Counter::default();
 
-    Ok(scores)
+    parse::numbered_lines(input)
+        .map(|(line, line_number)| {
+            let card = parse::line(DAY, line, line_number, parse_line)?;
+            let copies = add_to_counter(&card, &mut counter)?;
+            let matches = card.matches();
+            let won = checked(DAY, copies.checked_mul(matches))?;
+            let details = json!({"matches": matches, "copies": copies, "won": won});
+            let contribution = checked(DAY, won.checked_add(1))?;
+            Ok(Explanation::new(line_number, line, &card, contribution, details))
+        })
+        .collect()
 }
 
-#[pyfunction]
-pub fn day04b(file_path: &str) -> PyResult<usize> {
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let mut counter = Counter::default();
-    for line in reader.lines() {
-        add_to_counter(&line?, &mut counter)?;
+    const CARD: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+
+    fn card(line: &str) -> Card {
+        parse::line(DAY, line, 1, parse_line).unwrap()
     }
 
-    Ok(counter.sum())
+    #[test]
+    fn parse_line_parts() {
+        let card = card(CARD);
+        assert_eq!(1, card.number);
+        assert_eq!(vec![41, 48, 83, 86, 17], card.winning);
+        assert_eq!(vec![83, 86, 6, 31, 17, 9, 48, 53], card.ours);
+    }
+
+    #[test]
+    fn parse_line_missing_separator() {
+        let error = parse::line(DAY, "Card 1: 41 48 83 86", 3, parse_line).err();
+        assert_eq!(
+            Some(AocError::Parse {
+                day: DAY,
+                line: 3,
+                column: 20,
+                text: String::new(),
+                expected: String::from("'|'"),
+            }),
+            error
+        );
+    }
+
+    #[test]
+    fn match_score() {
+        assert_eq!(Ok(8), get_match_score(&card(CARD)));
+        assert_eq!(
+            Ok(0),
+            get_match_score(&card("Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"))
+        );
+    }
 }
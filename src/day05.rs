@@ -1,141 +1,124 @@
-use pyo3::exceptions::PyValueError;
-use pyo3::prelude::*;
-use ranges::{GenericRange, OperationResult, Ranges};
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::error::{checked, AocError};
+use crate::input;
+use crate::parse::{self, Block, Parser};
+use ranges::{GenericRange, Ranges};
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::ops::{Bound, Range, RangeBounds};
+use tracing::{debug, trace};
 
-type LinesIter = std::io::Lines<BufReader<File>>;
-// type RangeList = Vec<Range<usize>>;
 type RangeList = Ranges<usize>;
 
+const DAY: u8 = 5;
+
 const START_NODE: &str = "seed";
 const END_NODE: &str = "location";
 
-#[derive(Debug)]
-struct MapChain {
-    maps: HashMap<String, Map>,
+/// the maps by the category they map from
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct MapChain {
+    maps: BTreeMap<String, Map>,
 }
 
 impl MapChain {
-    fn from_lines(lines: &mut LinesIter) -> PyResult<MapChain> {
-        let mut maps = HashMap::new();
+    /// every block is a map, like the `seed-to-soil` block
+    pub fn from_blocks(blocks: &[Block<'_>]) -> Result<MapChain, AocError> {
+        let mut maps = BTreeMap::new();
+
+        for block in blocks {
+            let map = Map::from_block(block)?;
+            maps.insert(map.from.clone(), map);
+        }
 
-        while let Some(line) = lines.next() {
-            let line = line?;
+        Ok(MapChain { maps })
+    }
 
-            if line == "" {
-                continue;
+    /// the maps from `seed` to `location`, in the order they are applied
+    fn path(&self) -> Result<Vec<&Map>, AocError> {
+        let mut current_node = self.get_node(START_NODE)?;
+        let mut path = vec![current_node];
+
+        while current_node.to != END_NODE {
+            // every map is used once at most, more steps means the maps go around in a circle
+            if path.len() == self.maps.len() {
+                return Err(AocError::unsolvable(
+                    DAY,
+                    &format!("the maps never reach '{}'", END_NODE),
+                ));
             }
 
-            let map = Map::from_lines(&line, lines)?;
-            maps.insert(map.from.clone(), map);
+            current_node = self.get_node(&current_node.to)?;
+            path.push(current_node);
         }
 
-        Ok(MapChain { maps })
+        Ok(path)
     }
 
-    fn walk_to_location(&self, start_from_seed: usize) -> PyResult<usize> {
-        let mut current_node = self
-            .maps
-            .get(START_NODE)
-            .ok_or_else(|| PyErr::new::<PyValueError, _>("node not found"))?;
+    pub fn walk_to_location(&self, start_from_seed: usize) -> Result<usize, AocError> {
         let mut next_seed = start_from_seed;
 
-        while current_node.from != END_NODE {
+        for current_node in self.path()? {
             next_seed = current_node.convert(next_seed)?;
-            if current_node.to == END_NODE {
-                break;
-            }
-
-            current_node = self
-                .maps
-                .get(&current_node.to)
-                .ok_or_else(|| PyErr::new::<PyValueError, _>("node not found"))?;
         }
 
         Ok(next_seed)
     }
 
-    fn walk_range_to_location(
+    pub fn walk_range_to_location(
         &self,
         start_from_seed_range: GenericRange<usize>,
-    ) -> PyResult<RangeList> {
-        let mut current_node = self
-            .maps
-            .get(START_NODE)
-            .ok_or_else(|| PyErr::new::<PyValueError, _>("node not found"))?;
+    ) -> Result<RangeList, AocError> {
         let mut next_seed_ranges = RangeList::from(start_from_seed_range);
 
-        while current_node.from != END_NODE {
+        for current_node in self.path()? {
             next_seed_ranges = current_node.convert_ranges(next_seed_ranges)?;
-            if current_node.to == END_NODE {
-                break;
-            }
-
-            current_node = self
-                .maps
-                .get(&current_node.to)
-                .ok_or_else(|| PyErr::new::<PyValueError, _>("node not found"))?;
         }
 
         Ok(next_seed_ranges)
     }
+
+    fn get_node(&self, from: &str) -> Result<&Map, AocError> {
+        self.maps
+            .get(from)
+            .ok_or_else(|| AocError::unsolvable(DAY, &format!("no map found from '{}'", from)))
+    }
 }
 
-#[derive(Debug, Default)]
-struct Map {
+#[derive(Debug, Default, Serialize)]
+pub struct Map {
     from: String,
     to: String,
     entries: Vec<MapEntry>,
 }
 
 impl Map {
-    fn from_lines(first_line: &str, lines: &mut LinesIter) -> PyResult<Map> {
-        let mut map = Map::default();
-
-        // parse name
-        let (mapping, _) = first_line
-            .split_once(" ")
-            .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?;
-        let (from, to) = mapping
-            .split_once("-to-")
-            .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?;
-        map.from = from.to_string();
-        map.to = to.to_string();
-
-        while let Some(line) = lines.next() {
-            let line = line?;
-
-            if line == "" {
-                return Ok(map);
-            }
-
-            let (source, destination, amount) =
-                if let Some((source_txt, destination_txt, amount_txt)) =
-                    line.split_once(" ").and_then(|(destination, rest)| {
-                        rest.split_once(" ")
-                            .map(|(source, amount)| (source, destination, amount))
-                    })
-                {
-                    (
-                        source_txt.parse()?,
-                        destination_txt.parse()?,
-                        amount_txt.parse()?,
-                    )
-                } else {
-                    return Err(PyErr::new::<PyValueError, _>("invalid line"));
-                };
-
-            let new_entry = MapEntry::new(source, destination, amount);
-            map.entries.push(new_entry);
-        }
-
-        Ok(map)
+    fn from_block(block: &Block<'_>) -> Result<Map, AocError> {
+        let ((from, to), entries) = block.parse(
+            DAY,
+            |parser| {
+                let from = parser.word()?;
+                parser.tag("-to-")?;
+                let to = parser.word()?;
+                parser.label("map")?;
+                Ok((from, to))
+            },
+            |parser| {
+                let destination = parser.number()?;
+                let source = parser.number()?;
+                let amount = parser.number()?;
+                MapEntry::new(source, destination, amount)
+            },
+        )?;
+
+        Ok(Map {
+            from: from.to_string(),
+            to: to.to_string(),
+            entries,
+        })
     }
 
-    fn convert(&self, source: usize) -> PyResult<usize> {
+    fn convert(&self, source: usize) -> Result<usize, AocError> {
         for entry in self.entries.iter() {
             if entry.source.contains(&source) {
                 let offset = source - entry.source.start;
@@ -147,59 +130,26 @@ impl Map {
         Ok(source)
     }
 
-    fn convert_ranges(&self, ranges: RangeList) -> PyResult<RangeList> {
-        // let mut output: Vec<usize> = Vec::new();
-        // for entry in self.entries.iter() {
-        //     // for range in ranges {
-
-        //     // }
-
-        // }
-        let source_ranges = self.source_ranges();
-
-        let intersect = (source_ranges.clone()) & (ranges.clone());
-        let difference = source_ranges ^ ranges;
-        let mut output = difference;
-
-        // map intersect to correct output range
+    fn convert_ranges(&self, ranges: RangeList) -> Result<RangeList, AocError> {
+        // numbers that no entry covers map to themselves
+        let mut output = ranges.clone() - self.source_ranges();
 
         for entry in self.entries.iter() {
-            for range in intersect.as_slice() {
-                // let intersect = (entry.source.clone()) & (ranges.clone());
-                let intersection = range.clone() & GenericRange::from(entry.source.clone());
-
-                match intersection {
-                    OperationResult::Empty => (),
-                    OperationResult::Single(found_range) => {
-                        let source = deref_bound(found_range.start_bound());
-                        let destination = deref_bound(found_range.end_bound());
-
-                        let offset_start =
-                            bound_to_inner(sub_off_bound(source, entry.source.start));
-                        let offset_end =
-                            bound_to_inner(sub_off_bound(destination, entry.source.start));
+            let overlap = ranges.clone() & GenericRange::from(entry.source.clone());
 
-                        dbg!(offset_start, offset_end);
+            for range in overlap.as_slice() {
+                let (start, end) = half_open(range);
+                trace!(start, end, "overlap with map entry");
 
-                        let final_source = entry.destination.start + offset_start;
-                        let final_destination = entry.destination.end + offset_end;
-
-                        // let final_source = deref_bound(found_range.start_bound());
-                        // let final_destination = deref_bound(found_range.end_bound());
-
-                        // let updated_source = add_to_bound(final_source, offset);
-                        // let updated_destination = add_to_bound(final_destination, offset);
-
-                        output.insert(final_source..final_destination);
-                    }
-                    _ => unreachable!(),
-                }
+                let destination = entry.destination.start;
+                output.insert(
+                    destination + (start - entry.source.start)
+                        ..destination + (end - entry.source.start),
+                );
             }
         }
 
-        // dbg!(&intersect);
-
-        Ok(RangeList::from(output))
+        Ok(output)
     }
 
     fn source_ranges(&self) -> RangeList {
@@ -210,97 +160,86 @@ impl Map {
     }
 }
 
-pub fn deref_bound<T: Copy>(bound: Bound<&T>) -> Bound<T> {
-    match bound {
-        Bound::Excluded(x) => Bound::Excluded(*x),
-        Bound::Included(x) => Bound::Included(*x),
-        Bound::Unbounded => Bound::Unbounded,
-    }
-}
-
-pub fn add_to_bound<T: std::ops::Add<Output = T>>(bound: Bound<T>, number: T) -> Bound<T> {
-    match bound {
-        Bound::Excluded(x) => Bound::Excluded(x + number),
-        Bound::Included(x) => Bound::Included(x + number),
-        Bound::Unbounded => Bound::Unbounded,
-    }
-}
-
-pub fn sub_off_bound<T: std::ops::Sub<Output = T>>(bound: Bound<T>, number: T) -> Bound<T> {
-    match bound {
-        Bound::Excluded(x) => Bound::Excluded(x - number),
-        Bound::Included(x) => Bound::Included(x - number),
-        Bound::Unbounded => Bound::Unbounded,
-    }
-}
-
-pub fn bound_to_inner(bound: Bound<usize>) -> usize {
-    match bound {
-        Bound::Excluded(x) => x,
-        Bound::Included(x) => x,
+/// the range as `start..end`, whatever bounds the set operations left on it
+fn half_open(range: &GenericRange<usize>) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(x) => *x,
+        Bound::Excluded(x) => x + 1,
+        Bound::Unbounded => usize::MIN,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(x) => x + 1,
+        Bound::Excluded(x) => *x,
         Bound::Unbounded => usize::MAX,
-    }
+    };
+    (start, end)
 }
 
-#[derive(Debug)]
-struct MapEntry {
+#[derive(Debug, Serialize)]
+pub struct MapEntry {
     source: Range<usize>,
     destination: Range<usize>,
 }
 
 impl MapEntry {
-    pub fn new(source: usize, destination: usize, amount: usize) -> MapEntry {
-        MapEntry {
-            source: source..(source + amount),
-            destination: destination..(destination + amount),
-        }
+    pub fn new(source: usize, destination: usize, amount: usize) -> Result<MapEntry, AocError> {
+        Ok(MapEntry {
+            source: source..checked(DAY, source.checked_add(amount))?,
+            destination: destination..checked(DAY, destination.checked_add(amount))?,
+        })
     }
 }
 
-fn parse_single_seeds(text: &str) -> PyResult<Vec<usize>> {
-    let (_, numbers_text) = text
-        .split_once(": ")
-        .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?;
-
-    numbers_text.split(" ").map(|x| Ok(x.parse()?)).collect()
+fn parse_single_seeds(parser: &mut Parser<'_>) -> Result<Vec<usize>, AocError> {
+    parser.label("seeds")?;
+    parser.numbers()
 }
 
-fn parse_range_seeds(text: &str) -> PyResult<RangeList> {
-    let (_, numbers_text) = text
-        .split_once(": ")
-        .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?;
-
-    let list_of_numbers: Vec<_> = numbers_text
-        .split(" ")
-        .map(|x| Ok::<_, PyErr>(x.parse()?))
-        .collect::<Result<_, _>>()?;
-
-    let mut ranges = Vec::new();
-    for items in list_of_numbers.chunks(2) {
-        if items.len() != 2 {
-            return Err(PyErr::new::<PyValueError, _>("invalid seeds range"));
-        }
-
-        let start = items[0];
-        let amount = items[1];
-        ranges.push(start..(start + amount))
+fn parse_range_seeds(parser: &mut Parser<'_>) -> Result<RangeList, AocError> {
+    let list_of_numbers = parse_single_seeds(parser)?;
+    if list_of_numbers.len() % 2 != 0 {
+        return Err(parser.error("pairs of seed ranges"));
     }
 
+    let ranges = list_of_numbers
+        .chunks(2)
+        .map(|items| Ok(items[0]..checked(DAY, items[0].checked_add(items[1]))?))
+        .collect::<Result<Vec<_>, AocError>>()?;
+
     Ok(RangeList::from(ranges))
 }
 
-#[pyfunction]
-pub fn day05a(file_path: &str) -> PyResult<usize> {
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
+/// the first block is the seeds line, the other blocks are the maps
+fn parse_input<'a, T>(
+    input: &'a str,
+    parse_seeds: impl FnOnce(&mut Parser<'a>) -> Result<T, AocError>,
+) -> Result<(T, MapChain), AocError> {
+    let blocks = parse::blocks(input);
+    let (seeds_block, map_blocks) = blocks
+        .split_first()
+        .ok_or_else(|| AocError::Input(String::from("empty file")))?;
+    let (seeds, _) = seeds_block.parse(DAY, parse_seeds, |parser| {
+        Err::<(), _>(parser.error("a blank line"))
+    })?;
+
+    Ok((seeds, MapChain::from_blocks(map_blocks)?))
+}
 
-    let mut lines_iter = reader.lines();
-    let seeds_text = lines_iter
-        .next()
-        .ok_or_else(|| PyErr::new::<PyValueError, _>("empty file"))?;
-    let seeds = parse_single_seeds(&seeds_text?)?;
+#[derive(Debug, Serialize)]
+pub struct Puzzle {
+    seeds: Vec<usize>,
+    maps: MapChain,
+}
 
-    let map_chain = MapChain::from_lines(&mut lines_iter)?;
+pub fn day05_parse(input: &str) -> Result<Puzzle, AocError> {
+    let input = &input::normalize(input);
+    let (seeds, maps) = parse_input(input, parse_single_seeds)?;
+    Ok(Puzzle { seeds, maps })
+}
+
+pub fn day05a(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    let (seeds, map_chain) = parse_input(input, parse_single_seeds)?;
 
     let mut minimum = usize::MAX;
 
@@ -314,30 +253,20 @@ pub fn day05a(file_path: &str) -> PyResult<usize> {
     Ok(minimum)
 }
 
-#[pyfunction]
-pub fn day05b(file_path: &str) -> PyResult<usize> {
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
-
-    let mut lines_iter = reader.lines();
-    let seeds_text = lines_iter
-        .next()
-        .ok_or_else(|| PyErr::new::<PyValueError, _>("empty file"))?;
-    let seeds = parse_range_seeds(&seeds_text?)?;
-
-    let map_chain = MapChain::from_lines(&mut lines_iter)?;
+/// walks whole seed ranges through the maps, a seed at a time would take too long
+pub fn day05b(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    let (seeds, map_chain) = parse_input(input, parse_range_seeds)?;
 
     let mut minimum = usize::MAX;
 
     for seed_range in seeds.as_slice() {
-        let location_ranges = map_chain.walk_range_to_location(seed_range.clone())?;
+        let location_ranges = map_chain.walk_range_to_location(*seed_range)?;
         let location_number = location_ranges
             .as_slice()
             .iter()
-            .inspect(|x| {
-                dbg!(x);
-            })
-            .map(|range| bound_to_inner(deref_bound(range.start_bound())))
+            .inspect(|range| debug!(?range, "location range"))
+            .map(|range| half_open(range).0)
             .min()
             .unwrap_or(usize::MAX);
 
@@ -348,3 +277,58 @@ pub fn day05b(file_path: &str) -> PyResult<usize> {
 
     Ok(minimum)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_from_block() {
+        let text = "seed-to-soil map:\n50 98 2\n52 50 48\n\nignored";
+        let blocks = parse::blocks(text);
+        let map = Map::from_block(&blocks[0]).unwrap();
+
+        assert_eq!("seed", map.from);
+        assert_eq!("soil", map.to);
+        assert_eq!(2, map.entries.len());
+        assert_eq!(Ok(50), map.convert(98));
+        assert_eq!(Ok(55), map.convert(53));
+        assert_eq!(Ok(10), map.convert(10));
+        assert_eq!(vec!["ignored"], blocks[1].lines);
+    }
+
+    #[test]
+    fn map_from_block_bad_entry() {
+        let blocks = parse::blocks("\nseed-to-soil map:\n50 98 x");
+        let error = Map::from_block(&blocks[0]).unwrap_err();
+        assert!(matches!(
+            error,
+            AocError::Parse {
+                line: 3,
+                column: 7,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn seeds() {
+        let seeds = |line| parse::line(DAY, line, 1, parse_single_seeds);
+        assert_eq!(Ok(vec![79, 14, 55, 13]), seeds("seeds: 79 14 55 13"));
+        assert_eq!(Ok(vec![79, 14]), seeds("seeds:79  14\r"));
+        assert!(parse::line(DAY, "seeds: 79 14 55", 1, parse_range_seeds).is_err());
+    }
+
+    #[test]
+    fn seeds_block_has_one_line() {
+        let error = parse_input("seeds: 79 14\n55 13\n", parse_single_seeds).unwrap_err();
+        assert!(matches!(
+            error,
+            AocError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            }
+        ));
+    }
+}
@@ -1,78 +1,204 @@
-use pyo3::exceptions::PyValueError;
-use pyo3::prelude::*;
-use std::fs::read_to_string;
-use std::str::Lines;
-
-fn parse_line_part_one<'a>(lines: &'a mut Lines) -> PyResult<(&'a str, Vec<i32>)> {
-    let (title, data_text) = lines
-        .next()
-        .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?
-        .split_once(":")
-        .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?;
-    let data = data_text
-        .split_ascii_whitespace()
-        .map(|x| x.parse())
-        .collect::<Result<_, _>>()?;
-    Ok((title, data))
+use crate::error::{checked, AocError};
+use crate::grid::Grid;
+use crate::input;
+use crate::parse::{self, Parser};
+use serde::Serialize;
+
+const DAY: u8 = 6;
+
+fn parse_line_part_one(parser: &mut Parser<'_>) -> Result<Vec<i32>, AocError> {
+    parser.numbers()
+}
+
+/// the spaces between the numbers do not count, they are one number
+fn parse_line_part_two(parser: &mut Parser<'_>) -> Result<i64, AocError> {
+    let data_text = parser.rest().trim_start();
+    let mut digits = String::new();
+    while !parser.is_done() {
+        digits.push_str(parser.digits()?);
+    }
+
+    digits
+        .parse()
+        .map_err(|_| parser.error_at(data_text, "a number"))
+}
+
+/// the `Time` and the `Distance` line, parsed with `parse_data`
+fn parse_input<'a, T>(
+    input: &'a str,
+    mut parse_data: impl FnMut(&mut Parser<'a>) -> Result<T, AocError>,
+) -> Result<(T, T), AocError> {
+    let mut lines = input
+        .lines()
+        .zip(1..)
+        .filter(|(line, _)| !line.trim().is_empty());
+    let mut next_line = |title: &str| {
+        let (line, line_number) = lines
+            .next()
+            .ok_or_else(|| AocError::Input(format!("expected a '{}' line", title)))?;
+        parse::line(DAY, line, line_number, |parser| {
+            parser.label(title)?;
+            parse_data(parser)
+        })
+    };
+
+    Ok((next_line("Time")?, next_line("Distance")?))
 }
 
-fn parse_line_part_two<'a>(lines: &'a mut Lines) -> PyResult<(&'a str, i64)> {
-    let (title, data_text) = lines
-        .next()
-        .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?
-        .split_once(":")
-        .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?;
-    let data = data_text.replace(" ", "").parse()?;
-    Ok((title, data))
+#[derive(Debug, Serialize)]
+pub struct Race {
+    time: i64,
+    distance: i64,
 }
 
-#[pyfunction]
-pub fn day06a(file_path: &str) -> PyResult<i32> {
-    let input = read_to_string(file_path)?;
-    let mut lines = input.lines();
-    let (first_title, time_data) = parse_line_part_one(&mut lines)?;
-    if first_title != "Time" {
-        return Err(PyErr::new::<PyValueError, _>("invalid Time data"));
+/// the races of part one, and the one long race of part two
+#[derive(Debug, Serialize)]
+pub struct Puzzle {
+    races: Vec<Race>,
+    long_race: Race,
+}
+
+pub fn day06_parse(input: &str) -> Result<Puzzle, AocError> {
+    let input = &input::normalize(input);
+    let (times, distances) = parse_input(input, parse_line_part_one)?;
+    let (time, distance) = parse_input(input, parse_line_part_two)?;
+    let races = times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race {
+            time: time.into(),
+            distance: distance.into(),
+        })
+        .collect();
+
+    Ok(Puzzle {
+        races,
+        long_race: Race { time, distance },
+    })
+}
+
+/// the amount of ways to hold the button that go further than `distance`
+fn ways_to_win(time: i64, distance: i64) -> i128 {
+    let (time, distance) = (i128::from(time), i128::from(distance));
+    let wins = |hold: i128| hold * (time - hold) > distance;
+
+    // holding longer goes further until half of the time, so the first win is found by bisecting
+    let half = time / 2;
+    if time < 0 || !wins(half) {
+        return 0;
     }
-    let (second_title, distance_data) = parse_line_part_one(&mut lines)?;
-    if second_title != "Distance" {
-        return Err(PyErr::new::<PyValueError, _>("invalid Distance data"));
+
+    let (mut low, mut high) = (0, half);
+    while low < high {
+        let middle = (low + high) / 2;
+        if wins(middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
     }
 
-    let mut score = 1;
-    for (time, distance) in time_data.into_iter().zip(distance_data.into_iter()) {
-        let mut i = 0;
+    time - low * 2 + 1
+}
 
-        while ((time * i - i * i) - distance) <= 0 {
-            i += 1;
-        }
+/// the races of part one as `[time, distance]` rows
+pub fn day06_races(input: &str) -> Result<Grid<i64>, AocError> {
+    let input = &input::normalize(input);
+    let (times, distances) = parse_input(input, parse_line_part_one)?;
+    if times.len() != distances.len() {
+        return Err(AocError::Input(format!(
+            "there are {} times but {} distances",
+            times.len(),
+            distances.len()
+        )));
+    }
 
-        let out = time - i * 2 + 1;
-        score *= out;
+    let cells = times
+        .into_iter()
+        .zip(distances)
+        .flat_map(|(time, distance)| [time.into(), distance.into()])
+        .collect::<Vec<_>>();
+    Ok(Grid::from_cells(2, cells.len() / 2, cells).expect("every race is two cells"))
+}
+
+/// the amount of ways to win every race, `times` and `distances` are the races side by side
+pub fn day06_ways_to_win(times: &[i64], distances: &[i64]) -> Result<Vec<i64>, AocError> {
+    if times.len() != distances.len() {
+        return Err(AocError::Input(format!(
+            "there are {} times but {} distances",
+            times.len(),
+            distances.len()
+        )));
     }
 
-    Ok(score)
+    times
+        .iter()
+        .zip(distances)
+        .map(|(time, distance)| checked(DAY, ways_to_win(*time, *distance).try_into().ok()))
+        .collect()
 }
 
-#[pyfunction]
-pub fn day06b(file_path: &str) -> PyResult<i64> {
-    let input = read_to_string(file_path)?;
-    let mut lines = input.lines();
-    let (first_title, time) = parse_line_part_two(&mut lines)?;
-    if first_title != "Time" {
-        return Err(PyErr::new::<PyValueError, _>("invalid Time data"));
+pub fn day06a(input: &str) -> Result<i32, AocError> {
+    let input = &input::normalize(input);
+    let (time_data, distance_data) = parse_input(input, parse_line_part_one)?;
+
+    let mut score: i32 = 1;
+    for (time, distance) in time_data.into_iter().zip(distance_data) {
+        let out = checked(
+            DAY,
+            ways_to_win(time.into(), distance.into()).try_into().ok(),
+        )?;
+        score = checked(DAY, score.checked_mul(out))?;
     }
-    let (second_title, distance) = parse_line_part_two(&mut lines)?;
-    if second_title != "Distance" {
-        return Err(PyErr::new::<PyValueError, _>("invalid Distance data"));
+
+    Ok(score)
+}
+
+pub fn day06b(input: &str) -> Result<i64, AocError> {
+    let input = &input::normalize(input);
+    let (time, distance) = parse_input(input, parse_line_part_two)?;
+
+    checked(DAY, ways_to_win(time, distance).try_into().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_part_one() {
+        assert_eq!(
+            Ok(vec![7, 15, 30]),
+            parse::line(DAY, "      7  15   30\r", 1, parse_line_part_one)
+        );
     }
 
-    let mut i = 0;
-    while ((time * i - i * i) - distance) <= 0 {
-        i += 1;
+    #[test]
+    fn line_part_two() {
+        assert_eq!(
+            Ok(71530),
+            parse::line(DAY, "      7  15   30", 1, parse_line_part_two)
+        );
     }
 
-    let score = time - i * 2 + 1;
+    #[test]
+    fn ways_to_win_races() {
+        assert_eq!(4, ways_to_win(7, 9));
+        assert_eq!(9, ways_to_win(30, 200));
+        assert_eq!(0, ways_to_win(7, 12));
+        assert_eq!(0, ways_to_win(-3, 0));
+    }
 
-    Ok(score)
+    #[test]
+    fn wrong_title() {
+        let error = day06a("Time: 7\nDistanse: 9").unwrap_err();
+        assert!(matches!(
+            error,
+            AocError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            }
+        ));
+    }
 }
@@ -1,24 +1,47 @@
+use crate::error::{checked, AocError};
+use crate::explain::Explanation;
+use crate::input;
+use crate::parse::{self, Parser};
+use crate::solver::Part;
 use lazy_static::lazy_static;
-use pyo3::exceptions::PyValueError;
-use pyo3::prelude::*;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use serde::Serialize;
+use serde_json::json;
 use std::str::FromStr;
 
+const DAY: u8 = 7;
+
 lazy_static! {
     static ref CARDS: Vec<char> = "23456789TJQKA".chars().collect();
 }
 
-#[derive(Debug, Eq, Ord)]
-struct Play {
+/// the names of the first number of [`Play::as_rank`]
+const KINDS: [&str; 7] = [
+    "high card",
+    "one pair",
+    "two pair",
+    "three of a kind",
+    "full house",
+    "four of a kind",
+    "five of a kind",
+];
+
+/// the cards are indexes in `23456789TJQKA`, `hand` is sorted from high to low
+#[derive(Debug, Eq, Serialize)]
+pub struct Play {
     hand: [usize; 5],
     original_hand: [usize; 5],
     bid: usize,
 }
 
+impl Ord for Play {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_rank().cmp(&other.as_rank())
+    }
+}
+
 impl PartialOrd for Play {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.as_rank().partial_cmp(&other.as_rank())
+        Some(self.cmp(other))
     }
 }
 
@@ -145,67 +168,146 @@ impl Play {
     }
 }
 
-impl FromStr for Play {
-    type Err = PyErr;
+impl Play {
+    fn parse(parser: &mut Parser<'_>) -> Result<Play, AocError> {
+        let hand_text = parser.take_while(|ch| !ch.is_whitespace());
+        if hand_text.chars().count() != 5 {
+            return Err(parser.error_at(hand_text, "a hand of 5 cards"));
+        }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (hand_text, bid_str) = s
-            .split_once(" ")
-            .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?;
         let mut original_hand = [0, 0, 0, 0, 0];
-
-        for (i, ch) in hand_text.chars().enumerate() {
-            original_hand[i] = CARDS
-                .iter()
-                .position(|n| n == &ch)
-                .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid card"))?;
+        for (i, (offset, ch)) in hand_text.char_indices().enumerate() {
+            original_hand[i] = CARDS.iter().position(|n| n == &ch).ok_or_else(|| {
+                let card = &hand_text[offset..(offset + ch.len_utf8())];
+                parser.error_at(card, "one of '23456789TJQKA'")
+            })?;
         }
 
-        let mut hand = original_hand.clone();
+        let mut hand = original_hand;
         hand.sort_unstable_by(|a, b| b.cmp(a));
 
         Ok(Play {
-            bid: bid_str.parse()?,
+            bid: parser.number()?,
             original_hand,
             hand,
         })
     }
 }
 
-#[pyfunction]
-pub fn day07_sort_cards(cards_text: &str) -> PyResult<Vec<Vec<usize>>> {
-    let mut plays: Vec<Play> = Vec::new();
-    for line in cards_text.lines() {
-        plays.push(line.parse()?);
+impl FromStr for Play {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(DAY, s);
+        let play = Play::parse(&mut parser)?;
+        parser.end()?;
+        Ok(play)
     }
+}
 
-    plays.sort();
+#[derive(Debug, Serialize)]
+pub struct Puzzle {
+    plays: Vec<Play>,
+}
 
-    Ok(plays.into_iter().map(|x| x.hand.to_vec()).collect())
+pub fn day07_parse(input: &str) -> Result<Puzzle, AocError> {
+    let input = &input::normalize(input);
+    Ok(Puzzle {
+        plays: parse::lines(DAY, input, Play::parse)?,
+    })
 }
 
-#[pyfunction]
-pub fn day07a(file_path: &str) -> PyResult<usize> {
-    let input = File::open(file_path)?;
-    let reader = BufReader::new(input);
+pub fn day07_sort_cards(cards_text: &str) -> Result<Vec<Vec<usize>>, AocError> {
+    let cards_text = &input::normalize(cards_text);
+    let mut plays = parse::lines(DAY, cards_text, Play::parse)?;
+    plays.sort();
 
-    let mut plays: Vec<Play> = Vec::new();
-    for line in reader.lines() {
-        plays.push(line?.parse()?);
-    }
+    Ok(plays.into_iter().map(|x| x.hand.to_vec()).collect())
+}
 
+pub fn day07a(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    let mut plays = parse::lines(DAY, input, Play::parse)?;
     plays.sort();
 
-    let mut total = 0;
+    let mut total: usize = 0;
 
     for (index, play) in plays.into_iter().enumerate() {
-        total += (index + 1) * play.bid
+        let winnings = (index + 1).checked_mul(play.bid);
+        total = checked(
+            DAY,
+            winnings.and_then(|winnings| total.checked_add(winnings)),
+        )?;
     }
 
     Ok(total)
 }
 
-#[pyfunction]
-pub fn day07b(_file_path: &str) -> PyResult<usize> {
-    todo!()
+/// the winnings of every play, in the order of the input
+pub fn day07a_explain(input: &str) -> Result<Vec<Explanation>, AocError> {
+    let input = &input::normalize(input);
+    let mut plays = parse::numbered_lines(input)
+        .map(|(line, line_number)| {
+            parse::line(DAY, line, line_number, Play::parse).map(|play| (line, line_number, play))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    plays.sort_by(|(_, _, left), (_, _, right)| left.cmp(right));
+
+    let mut explanations = Vec::new();
+    for (index, (line, line_number, play)) in plays.into_iter().enumerate() {
+        let rank = index + 1;
+        let winnings = checked(DAY, rank.checked_mul(play.bid))?;
+        let details = json!({"kind": KINDS[play.as_rank().0], "rank": rank});
+        explanations.push(Explanation::new(
+            line_number,
+            line,
+            &play,
+            winnings,
+            details,
+        ));
+    }
+    explanations.sort_by_key(|explanation| explanation.line);
+
+    Ok(explanations)
+}
+
+pub fn day07b(_input: &str) -> Result<usize, AocError> {
+    Err(AocError::not_solved(DAY, Part::B))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn play_from_str() {
+        let play: Play = "32T3K 765".parse().unwrap();
+        assert_eq!([1, 0, 8, 1, 11], play.original_hand);
+        assert_eq!([11, 8, 1, 1, 0], play.hand);
+        assert_eq!(765, play.bid);
+    }
+
+    #[test]
+    fn play_from_str_unknown_card() {
+        let error = "32X3K 765".parse::<Play>().unwrap_err();
+        assert!(matches!(error, AocError::Parse { column: 3, .. }));
+    }
+
+    #[test]
+    fn play_from_str_too_many_cards() {
+        let error = "32T3KA 765".parse::<Play>().unwrap_err();
+        assert!(matches!(error, AocError::Parse { column: 1, .. }));
+    }
+
+    #[test]
+    fn ranks() {
+        let rank = |text: &str| text.parse::<Play>().unwrap().as_rank().0;
+        assert_eq!(6, rank("AAAAA 1"));
+        assert_eq!(5, rank("AA8AA 1"));
+        assert_eq!(4, rank("23332 1"));
+        assert_eq!(3, rank("TTT98 1"));
+        assert_eq!(2, rank("23432 1"));
+        assert_eq!(1, rank("A23A4 1"));
+        assert_eq!(0, rank("23456 1"));
+    }
 }
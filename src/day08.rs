@@ -1,13 +1,18 @@
-use pyo3::exceptions::PyValueError;
-use pyo3::prelude::*;
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::error::{checked, AocError};
+use crate::input;
+use crate::parse::{self, Parser};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tracing::debug;
+
+pub type Lookup = BTreeMap<String, (String, String)>;
+
+const DAY: u8 = 8;
 
 const START: &str = "AAA";
 const END: &str = "ZZZ";
 
-struct InstructionIterator {
+pub struct InstructionIterator {
     instructions: Vec<char>,
     current_index: usize,
 }
@@ -16,59 +21,93 @@ impl Iterator for InstructionIterator {
     type Item = char;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(ch) = self.instructions.get(self.current_index) {
-            self.current_index += 1;
-            Some(*ch)
-        } else {
+        if self.current_index == self.instructions.len() {
             self.current_index = 0;
-            self.next()
         }
+
+        let ch = self.instructions.get(self.current_index)?;
+        self.current_index += 1;
+        Some(*ch)
     }
 }
 
-fn parse_file(
-    file_path: &str,
-) -> PyResult<(InstructionIterator, HashMap<String, (String, String)>)> {
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
-    let mut lines = reader.lines();
+fn parse_instructions(parser: &mut Parser<'_>) -> Result<Vec<char>, AocError> {
+    let instructions_txt = parser.word()?;
+    instructions_txt
+        .char_indices()
+        .map(|(offset, ch)| match ch {
+            'L' | 'R' => Ok(ch),
+            _ => Err(parser.error_at(&instructions_txt[offset..], "'L' or 'R'")),
+        })
+        .collect()
+}
+
+fn parse_node(parser: &mut Parser<'_>) -> Result<(String, (String, String)), AocError> {
+    let from = parser.word()?;
+    parser.tag("=")?;
+    parser.tag("(")?;
+    let left = parser.word()?;
+    parser.tag(",")?;
+    let right = parser.word()?;
+    parser.tag(")")?;
 
-    let instructions_txt = lines
-        .next()
-        .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))??;
+    Ok((from.to_string(), (left.to_string(), right.to_string())))
+}
+
+/// the instructions line, a blank line and then the nodes
+pub fn parse_input(input: &str) -> Result<(InstructionIterator, Lookup), AocError> {
+    let input = &input::normalize(input);
+    let blocks = parse::blocks(input);
+    let (instructions_block, node_blocks) = blocks
+        .split_first()
+        .ok_or_else(|| AocError::Input(String::from("empty file")))?;
+    let (instructions, _) = instructions_block.parse(DAY, parse_instructions, |parser| {
+        Err::<(), _>(parser.error("a blank line"))
+    })?;
     let instructions_iter = InstructionIterator {
-        instructions: instructions_txt.chars().collect(),
+        instructions,
         current_index: 0,
     };
-    lines.next();
-
-    let mut lookup = HashMap::new();
-
-    for line in lines {
-        let line = line?;
-        let (from, left_right_txt) = line
-            .split_once(" = (")
-            .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?;
-        let (left, right_txt) = left_right_txt
-            .split_once(", ")
-            .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?;
-        let right = &right_txt[..(right_txt.len() - 1)];
-        lookup.insert(from.to_string(), (left.to_string(), right.to_string()));
+
+    let mut lookup = BTreeMap::new();
+    for block in node_blocks {
+        for (line, line_number) in block.numbered_lines() {
+            let (from, left_right) = parse::line(DAY, line, line_number, parse_node)?;
+            lookup.insert(from, left_right);
+        }
     }
 
     Ok((instructions_iter, lookup))
 }
 
-#[pyfunction]
-pub fn day08a(file_path: &str) -> PyResult<usize> {
-    let (instructions_iter, lookup) = parse_file(file_path)?;
+/// the instructions and the `(left, right)` of every node
+#[derive(Debug, Serialize)]
+pub struct Puzzle {
+    instructions: String,
+    nodes: Lookup,
+}
+
+pub fn day08_parse(input: &str) -> Result<Puzzle, AocError> {
+    let input = &input::normalize(input);
+    let (instructions_iter, nodes) = parse_input(input)?;
+    Ok(Puzzle {
+        instructions: instructions_iter.instructions.into_iter().collect(),
+        nodes,
+    })
+}
+
+pub fn day08a(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    let (instructions_iter, lookup) = parse_input(input)?;
+
+    // a walk that takes a step for every (node, instruction) pair goes around in a circle
+    let max_steps = lookup.len() * instructions_iter.instructions.len();
 
     let mut current_key = START.to_string();
-    let mut latest_index = usize::MAX;
-    for (index, instruction) in instructions_iter.enumerate() {
+    for (index, instruction) in instructions_iter.enumerate().take(max_steps) {
         let value = lookup
             .get(&current_key)
-            .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid lookup"))?;
+            .ok_or_else(|| AocError::unsolvable(DAY, "walked to a node that does not exist"))?;
         let new_key = if instruction == 'L' {
             &value.0
         } else {
@@ -77,53 +116,140 @@ pub fn day08a(file_path: &str) -> PyResult<usize> {
         current_key = new_key.to_string();
 
         if current_key == END {
-            latest_index = index;
-            break;
+            return Ok(index + 1);
         }
     }
 
-    Ok(latest_index + 1)
+    Err(AocError::unsolvable(
+        DAY,
+        &format!("never walks from '{}' to '{}'", START, END),
+    ))
 }
 
-#[pyfunction]
-pub fn day08b(file_path: &str) -> PyResult<usize> {
-    let (instructions_iter, lookup) = parse_file(file_path)?;
-    let _ = instructions_iter.instructions.len();
-
-    let mut counter: HashMap<String, usize> = HashMap::new();
-    let mut starting_points: Vec<String> = lookup
-        .keys()
-        .filter(|key| key.ends_with('A'))
-        .cloned()
-        .collect();
-    let mut last_round = usize::MAX / 2;
-    for (rounds, instruction) in instructions_iter.enumerate() {
-        for points in starting_points.iter_mut() {
-            let value = lookup
-                .get(points)
-                .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid lookup"))?;
-            let new_key = if instruction == 'L' {
-                &value.0
-            } else {
-                &value.1
-            };
-
-            let counter_entry = counter.entry(new_key.to_string()).or_default();
-            *counter_entry += 1;
-
-            *points = new_key.to_string();
-        }
+/// the steps from `start` to the first node that ends with a 'Z'
+fn steps_to_end(start: &str, instructions: &[char], lookup: &Lookup) -> Result<usize, AocError> {
+    // a walk that takes a step for every (node, instruction) pair goes around in a circle
+    let max_steps = lookup.len() * instructions.len();
 
-        if starting_points.iter().all(|x| x.ends_with('Z')) {
-            last_round = rounds;
-            break;
-        }
+    let mut current_key = start;
+    for (index, instruction) in instructions.iter().cycle().enumerate().take(max_steps) {
+        let value = lookup
+            .get(current_key)
+            .ok_or_else(|| AocError::unsolvable(DAY, "walked to a node that does not exist"))?;
+        current_key = if *instruction == 'L' {
+            &value.0
+        } else {
+            &value.1
+        };
 
-        if rounds > 300 {
-            dbg!(counter);
-            break;
+        if current_key.ends_with('Z') {
+            return Ok(index + 1);
         }
     }
 
-    Ok(last_round + 1)
+    Err(AocError::unsolvable(
+        DAY,
+        &format!("never walks from '{}' to a node that ends with 'Z'", start),
+    ))
+}
+
+fn greatest_common_divisor(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        greatest_common_divisor(b, a % b)
+    }
+}
+
+/// every walk from a node that ends with an 'A' goes around in a circle as long as the way to its
+/// first 'Z', so they all end on a 'Z' at the same time after the least common multiple of those
+pub fn day08b(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    let (instructions_iter, lookup) = parse_input(input)?;
+
+    let mut steps = None;
+    for start in lookup.keys().filter(|key| key.ends_with('A')) {
+        let to_end = steps_to_end(start, &instructions_iter.instructions, &lookup)?;
+        debug!(start, to_end, "walked to the end");
+
+        steps = Some(match steps {
+            None => to_end,
+            Some(steps) => checked(
+                DAY,
+                (steps / greatest_common_divisor(steps, to_end)).checked_mul(to_end),
+            )?,
+        });
+    }
+
+    steps.ok_or_else(|| AocError::unsolvable(DAY, "there is no node that ends with an 'A'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instructions_repeat() {
+        let (instructions, _) = parse_input("LR\n\nAAA = (BBB, CCC)").unwrap();
+        assert_eq!(
+            vec!['L', 'R', 'L', 'R', 'L'],
+            instructions.take(5).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn parse_lookup() {
+        let (_, lookup) = parse_input("LR\n\nAAA = (BBB, CCC)").unwrap();
+        assert_eq!(
+            Some(&(String::from("BBB"), String::from("CCC"))),
+            lookup.get("AAA")
+        );
+    }
+
+    #[test]
+    fn parse_lookup_error() {
+        let error = parse_input("LR\n\nAAA = (BBB CCC)").err().unwrap();
+        assert!(matches!(
+            error,
+            AocError::Parse {
+                line: 3,
+                column: 12,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn parse_input_crlf() {
+        let (_, lookup) = parse_input("LR\r\n\r\nAAA = (BBB,CCC)\r\n").unwrap();
+        assert_eq!(
+            Some(&(String::from("BBB"), String::from("CCC"))),
+            lookup.get("AAA")
+        );
+    }
+
+    #[test]
+    fn ghosts_meet() {
+        let input = "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n\
+                     22A = (22B, XXX)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n\
+                     22Z = (22B, 22B)\nXXX = (XXX, XXX)";
+        assert_eq!(Ok(6), day08b(input));
+        assert!(matches!(
+            day08b("L\n\nAAA = (AAA, AAA)"),
+            Err(AocError::Unsolvable { .. })
+        ));
+    }
+
+    #[test]
+    fn unknown_instruction() {
+        let error = parse_input("LRX\n\nAAA = (BBB, CCC)").err().unwrap();
+        assert!(matches!(
+            error,
+            AocError::Parse {
+                line: 1,
+                column: 3,
+                ..
+            }
+        ));
+    }
 }
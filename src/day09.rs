@@ -1,26 +1,35 @@
-use pyo3::exceptions::PyValueError;
-use pyo3::prelude::*;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::error::{checked, AocError};
+use crate::explain::Explanation;
+use crate::grid::Grid;
+use crate::input;
+use crate::parse::{self, Parser};
+use crate::solver::Part;
+use serde::Serialize;
+use serde_json::json;
 use std::str::FromStr;
 
-#[derive(Debug)]
-struct Line(Vec<i64>);
+const DAY: u8 = 9;
+
+#[derive(Debug, Serialize)]
+pub struct Line(Vec<i64>);
 
 impl FromStr for Line {
-    type Err = PyErr;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let data = s
-            .split_ascii_whitespace()
-            .map(FromStr::from_str)
-            .collect::<Result<_, _>>()?;
-        Ok(Line(data))
+        let mut parser = Parser::new(DAY, s);
+        let line = Line::parse(&mut parser)?;
+        parser.end()?;
+        Ok(line)
     }
 }
 
 impl Line {
-    fn figure_out_next_item(self) -> PyResult<i64> {
+    fn parse(parser: &mut Parser<'_>) -> Result<Line, AocError> {
+        Ok(Line(parser.numbers()?))
+    }
+
+    pub fn figure_out_next_item(self) -> Result<i64, AocError> {
         let (mut next, starting_positions) = self.figure_out_starting_positions()?;
 
         next.0.push(0);
@@ -30,7 +39,7 @@ impl Line {
             row.push(start_item);
             for item in last_line.0.iter() {
                 let last = row.last().expect("last always exist");
-                row.push(last + item);
+                row.push(checked(DAY, last.checked_add(*item))?);
             }
             last_line = Line(row);
         }
@@ -39,43 +48,55 @@ impl Line {
         Ok(*last)
     }
 
-    fn figure_out_previous_item(self) -> PyResult<i64> {
+    pub fn figure_out_previous_item(self) -> Result<i64, AocError> {
         let (next, starting_positions) = self.figure_out_starting_positions()?;
 
         let mut first_number = *next
             .0
             .first()
-            .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid first item"))?;
+            .ok_or_else(|| AocError::unsolvable(DAY, "history without any numbers"))?;
         for start_item in starting_positions.into_iter().rev() {
-            first_number = start_item - first_number;
+            first_number = checked(DAY, start_item.checked_sub(first_number))?;
         }
 
         Ok(first_number)
     }
 
-    fn figure_out_starting_positions(mut self) -> PyResult<(Self, Vec<i64>)> {
+    fn figure_out_starting_positions(mut self) -> Result<(Self, Vec<i64>), AocError> {
         let mut starting_positions = Vec::new();
 
         while !self.is_all_zeroes() {
             starting_positions.push(
-                *(&self.0)
+                *self
+                    .0
                     .first()
-                    .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid first item"))?,
+                    .ok_or_else(|| AocError::unsolvable(DAY, "history without any numbers"))?,
             );
-            self = self.calculate_difference();
+            self = self.calculate_difference()?;
         }
 
         Ok((self, starting_positions))
     }
 
-    fn calculate_difference(&self) -> Line {
-        Line(
-            self.0
-                .iter()
-                .zip(self.0.iter().skip(1))
-                .map(|(left, right)| right - left)
-                .collect(),
-        )
+    fn calculate_difference(&self) -> Result<Line, AocError> {
+        self.0
+            .iter()
+            .zip(self.0.iter().skip(1))
+            .map(|(left, right)| checked(DAY, right.checked_sub(*left)))
+            .collect::<Result<_, _>>()
+            .map(Line)
+    }
+
+    /// the line and its differences, down to the first row of zeroes
+    fn differences(&self) -> Result<Vec<Vec<i64>>, AocError> {
+        let mut rows = vec![self.0.clone()];
+        let mut row = Line(self.0.clone());
+        while !row.is_all_zeroes() {
+            row = row.calculate_difference()?;
+            rows.push(row.0.clone());
+        }
+
+        Ok(rows)
     }
 
     fn is_all_zeroes(&self) -> bool {
@@ -83,30 +104,114 @@ impl Line {
     }
 }
 
-#[pyfunction]
-pub fn day09a(file_path: &str) -> PyResult<i64> {
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
+#[derive(Debug, Serialize)]
+pub struct Puzzle {
+    lines: Vec<Line>,
+}
+
+pub fn day09_parse(input: &str) -> Result<Puzzle, AocError> {
+    let input = &input::normalize(input);
+    Ok(Puzzle {
+        lines: parse::lines(DAY, input, Line::parse)?,
+    })
+}
 
-    let mut sum = 0;
-    for line in reader.lines() {
-        let data: Line = line?.parse()?;
-        sum += data.figure_out_next_item()?;
+/// the histories as rows, they should all be as long
+pub fn day09_histories(input: &str) -> Result<Grid<i64>, AocError> {
+    let input = &input::normalize(input);
+    let histories = parse::lines(DAY, input, Line::parse)?;
+    let width = histories.first().map_or(0, |history| history.0.len());
+    if let Some(index) = histories.iter().position(|history| history.0.len() != width) {
+        return Err(AocError::Input(format!(
+            "history {} has {} numbers, the ones before have {}",
+            index + 1,
+            histories[index].0.len(),
+            width
+        )));
     }
 
-    Ok(sum)
+    let height = histories.len();
+    let cells = histories.into_iter().flat_map(|history| history.0).collect();
+    Ok(Grid::from_cells(width, height, cells).expect("every history is as long"))
 }
 
-#[pyfunction]
-pub fn day09b(file_path: &str) -> PyResult<i64> {
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
+/// the next number of every history for part a, the previous one for part b
+pub fn day09_extrapolate(histories: Vec<Vec<i64>>, part: Part) -> Result<Vec<i64>, AocError> {
+    histories
+        .into_iter()
+        .map(|history| match part {
+            Part::A => Line(history).figure_out_next_item(),
+            Part::B => Line(history).figure_out_previous_item(),
+        })
+        .collect()
+}
 
-    let mut sum = 0;
-    for line in reader.lines() {
-        let data: Line = line?.parse()?;
-        sum += data.figure_out_previous_item()?;
+pub fn day09a(input: &str) -> Result<i64, AocError> {
+    let input = &input::normalize(input);
+    let mut sum: i64 = 0;
+    for data in parse::lines(DAY, input, Line::parse)? {
+        sum = checked(DAY, sum.checked_add(data.figure_out_next_item()?))?;
     }
 
     Ok(sum)
 }
+
+pub fn day09b(input: &str) -> Result<i64, AocError> {
+    let input = &input::normalize(input);
+    let mut sum: i64 = 0;
+    for data in parse::lines(DAY, input, Line::parse)? {
+        sum = checked(DAY, sum.checked_add(data.figure_out_previous_item()?))?;
+    }
+
+    Ok(sum)
+}
+
+fn explain_lines(
+    input: &str,
+    extrapolate: fn(Line) -> Result<i64, AocError>,
+) -> Result<Vec<Explanation>, AocError> {
+    parse::numbered_lines(input)
+        .map(|(line, line_number)| {
+            let history = parse::line(DAY, line, line_number, Line::parse)?;
+            let details = json!({"differences": history.differences()?});
+            let parsed = serde_json::to_value(&history).expect("a line is valid json");
+            let value = extrapolate(history)?;
+            Ok(Explanation::new(line_number, line, &parsed, value, details))
+        })
+        .collect()
+}
+
+pub fn day09a_explain(input: &str) -> Result<Vec<Explanation>, AocError> {
+    let input = &input::normalize(input);
+    explain_lines(input, Line::figure_out_next_item)
+}
+
+pub fn day09b_explain(input: &str) -> Result<Vec<Explanation>, AocError> {
+    let input = &input::normalize(input);
+    explain_lines(input, Line::figure_out_previous_item)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_and_previous() {
+        let line: Line = "10 13 16 21 30 45".parse().unwrap();
+        assert_eq!(Ok(68), line.figure_out_next_item());
+        let line: Line = "10 13 16 21 30 45".parse().unwrap();
+        assert_eq!(Ok(5), line.figure_out_previous_item());
+    }
+
+    #[test]
+    fn not_a_number() {
+        let error = "1 2 x".parse::<Line>().unwrap_err();
+        assert!(matches!(error, AocError::Parse { column: 5, .. }));
+    }
+
+    #[test]
+    fn negative_numbers() {
+        let line: Line = "-1 -2 -3".parse().unwrap();
+        assert_eq!(Ok(-4), line.figure_out_next_item());
+    }
+}
@@ -1,10 +1,14 @@
-use pyo3::exceptions::PyValueError;
-use pyo3::prelude::*;
-use std::collections::{BTreeMap, HashMap};
-use std::fs::read_to_string;
+use crate::error::AocError;
+use crate::grid::{Direction, Grid, Position};
+use crate::input;
+use crate::render::{Mark, Picture};
+use crate::solver::Part;
+use serde::Serialize;
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq)]
+const DAY: u8 = 10;
+
+#[derive(Debug, PartialEq, Serialize)]
 enum Pipe {
     Start,
     Vertical,
@@ -15,6 +19,9 @@ enum Pipe {
     BendSouthEast,
 }
 
+const PIPES: &str = "S|-LJ7F";
+
+/// `Err` for the ground, `.`, and for characters that are not a pipe
 impl TryFrom<char> for Pipe {
     type Error = ();
 
@@ -27,8 +34,7 @@ impl TryFrom<char> for Pipe {
             'J' => Pipe::BendNorthWest,
             '7' => Pipe::BendSouthWest,
             'F' => Pipe::BendSouthEast,
-            '.' => return Err(()),
-            _ => unreachable!(),
+            _ => return Err(()),
         };
 
         Ok(res)
@@ -36,146 +42,117 @@ impl TryFrom<char> for Pipe {
 }
 
 impl Pipe {
-    fn allowed_to_go_right(&self, right: &Pipe) -> bool {
-        match self {
-            Pipe::Start | Pipe::Horizontal | Pipe::BendNorthEast | Pipe::BendSouthEast => {
-                [Pipe::Horizontal, Pipe::BendNorthWest, Pipe::BendSouthWest].contains(right)
-            }
-            _ => false,
-        }
-    }
-
-    fn allowed_to_go_left(&self, left: &Pipe) -> bool {
+    /// whether the pipe has an opening to `direction`, the start could go anywhere
+    fn opens_to(&self, direction: Direction) -> bool {
         match self {
-            Pipe::Start | Pipe::Horizontal | Pipe::BendNorthWest | Pipe::BendSouthWest => {
-                [Pipe::Horizontal, Pipe::BendNorthEast, Pipe::BendSouthEast].contains(left)
-            }
-            _ => false,
+            Pipe::Start => true,
+            Pipe::Vertical => matches!(direction, Direction::Up | Direction::Down),
+            Pipe::Horizontal => matches!(direction, Direction::Left | Direction::Right),
+            Pipe::BendNorthEast => matches!(direction, Direction::Up | Direction::Right),
+            Pipe::BendNorthWest => matches!(direction, Direction::Up | Direction::Left),
+            Pipe::BendSouthWest => matches!(direction, Direction::Down | Direction::Left),
+            Pipe::BendSouthEast => matches!(direction, Direction::Down | Direction::Right),
         }
     }
 
-    fn allowed_to_go_up(&self, up: &Pipe) -> bool {
-        match self {
-            Pipe::Start | Pipe::Vertical | Pipe::BendNorthEast | Pipe::BendNorthWest => {
-                [Pipe::Vertical, Pipe::BendSouthEast, Pipe::BendSouthWest].contains(up)
-            }
-            _ => false,
-        }
+    fn connects_to(&self, next: &Pipe, direction: Direction) -> bool {
+        self.opens_to(direction) && next.opens_to(direction.opposite())
     }
 
-    fn allowed_to_go_down(&self, up: &Pipe) -> bool {
+    /// the box drawing character of the pipe
+    fn drawing(&self) -> char {
         match self {
-            Pipe::Start | Pipe::Vertical | Pipe::BendSouthEast | Pipe::BendSouthWest => {
-                [Pipe::Vertical, Pipe::BendNorthEast, Pipe::BendNorthWest].contains(up)
-            }
-            _ => false,
+            Pipe::Start => 'S',
+            Pipe::Vertical => '│',
+            Pipe::Horizontal => '─',
+            Pipe::BendNorthEast => '└',
+            Pipe::BendNorthWest => '┘',
+            Pipe::BendSouthWest => '┐',
+            Pipe::BendSouthEast => '┌',
         }
     }
 }
 
-/// for once I did it properly, so x and y are like math graphing (instead of computer graphing)
-/// so x goes left to right increasing, and y goes down to up increasing.
-/// also the indexes are 1 based (instead of zero)
-#[derive(Debug)]
-struct Field {
-    pipes: BTreeMap<(usize, usize), Pipe>,
-    size: (usize, usize),
-    start_position: Option<(usize, usize)>,
+/// the pipes on the grid, `None` is ground
+#[derive(Debug, Serialize)]
+pub struct Field {
+    grid: Grid<Option<Pipe>>,
+    start_position: Option<Position>,
 }
 
 impl FromStr for Field {
-    type Err = PyErr;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut pipes = BTreeMap::new();
-        let mut start_position = None;
-        for (line, y) in s.lines().rev().zip(1..) {
-            for (ch, x) in line.chars().zip(1..) {
-                if let Ok(pipe) = ch.try_into() {
-                    if pipe == Pipe::Start {
-                        start_position = Some((x, y));
-                    }
-                    pipes.insert((x, y), pipe);
-                }
-            }
+        let chars: Grid<char> = s.parse()?;
+        if chars.height() == 0 {
+            return Err(AocError::Input(String::from("empty file")));
+        }
+
+        let unknown = chars
+            .iter()
+            .find(|(_, ch)| **ch != '.' && !PIPES.contains(**ch));
+        if let Some(((x, y), ch)) = unknown {
+            return Err(AocError::Parse {
+                day: DAY,
+                line: y + 1,
+                column: x + 1,
+                text: ch.to_string(),
+                expected: format!("one of '{}' or '.'", PIPES),
+            });
         }
 
-        let size_y = s.lines().count();
-        let size_x = s
-            .lines()
-            .next()
-            .map(|line| line.len())
-            .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid amount of lines"))?;
+        let grid = chars.map(|ch| Pipe::try_from(*ch).ok());
+        let start_position = grid
+            .iter()
+            .find(|(_, pipe)| pipe == &&Some(Pipe::Start))
+            .map(|(position, _)| position);
 
         Ok(Field {
-            pipes,
-            size: (size_x, size_y),
+            grid,
             start_position,
         })
     }
 }
 
 impl Field {
-    fn find_loop(&self) -> Vec<(usize, usize)> {
+    /// the order the directions are tried in, the loop is walked clockwise when it can
+    const DIRECTIONS: [Direction; 4] = [
+        Direction::Right,
+        Direction::Up,
+        Direction::Left,
+        Direction::Down,
+    ];
+
+    fn pipe(&self, position: Position) -> Option<&Pipe> {
+        self.grid.get(position).and_then(Option::as_ref)
+    }
+
+    pub fn find_loop(&self) -> Vec<Position> {
         let mut path = Vec::new();
-        if self.start_position.is_none() {
+        let Some(starting_position) = self.start_position else {
             return path;
-        }
+        };
 
-        let starting_position = self.start_position.unwrap();
+        let mut visited = Grid::filled(self.grid.width(), self.grid.height(), false);
         let mut current_position = starting_position;
-        let mut current_pipe = self.pipes.get(&starting_position).unwrap();
 
-        loop {
+        'walk: loop {
             path.push(current_position);
+            visited[current_position] = true;
+            let current_pipe = self.pipe(current_position).expect("only walks over pipes");
 
-            if let Some(right) = self
-                .pipes
-                .get(&(current_position.0 + 1, current_position.1))
-            {
-                if path.contains(&(current_position.0 + 1, current_position.1)) {
-                    ()
-                } else if current_pipe.allowed_to_go_right(right) {
-                    current_pipe = right;
-                    current_position = (current_position.0 + 1, current_position.1);
+            for direction in Self::DIRECTIONS {
+                let Some(next_position) = self.grid.step(current_position, direction) else {
                     continue;
-                }
-            }
-
-            if let Some(up) = self
-                .pipes
-                .get(&(current_position.0, current_position.1 + 1))
-            {
-                if path.contains(&(current_position.0, current_position.1 + 1)) {
-                    ()
-                } else if current_pipe.allowed_to_go_up(up) {
-                    current_pipe = up;
-                    current_position = (current_position.0, current_position.1 + 1);
+                };
+                let Some(next_pipe) = self.pipe(next_position) else {
                     continue;
-                }
-            }
-
-            if let Some(x_next) = current_position.0.checked_sub(1) {
-                if path.contains(&(x_next, current_position.1)) {
-                    ()
-                } else if let Some(left) = self.pipes.get(&(x_next, current_position.1)) {
-                    if current_pipe.allowed_to_go_left(left) {
-                        current_pipe = left;
-                        current_position = (x_next, current_position.1);
-                        continue;
-                    }
-                }
-            }
+                };
 
-            if let Some(y_next) = current_position.1.checked_sub(1) {
-                if path.contains(&(current_position.0, y_next)) {
-                    ()
-                } else if let Some(down) = self.pipes.get(&(current_position.0, y_next)) {
-                    if current_pipe.allowed_to_go_down(down) {
-                        current_pipe = down;
-                        current_position = (current_position.0, y_next);
-                        continue;
-                    }
+                if !visited[next_position] && current_pipe.connects_to(next_pipe, direction) {
+                    current_position = next_position;
+                    continue 'walk;
                 }
             }
 
@@ -187,15 +164,87 @@ impl Field {
     }
 }
 
-#[pyfunction]
-pub fn day10a(file_path: &str) -> PyResult<usize> {
-    let text = read_to_string(file_path)?;
-    let field: Field = text.parse()?;
+pub fn day10_parse(input: &str) -> Result<Field, AocError> {
+    let input = &input::normalize(input);
+    input.parse()
+}
+
+/// the tiles as their ascii bytes
+pub fn day10_tiles(input: &str) -> Result<Grid<u8>, AocError> {
+    let input = &input::normalize(input);
+    let _: Field = input.parse()?;
+    let chars: Grid<char> = input.parse()?;
+    // the field only accepts `.` and the pipes, so every char is ascii
+    Ok(chars.map(|ch| *ch as u8))
+}
+
+/// the loop, its start and the pipes that are not part of it, drawn with box drawing characters
+pub fn day10_render(input: &str) -> Result<Picture, AocError> {
+    let input = &input::normalize(input);
+    let field: Field = input.parse()?;
+    let cells = field
+        .grid
+        .map(|pipe| pipe.as_ref().map_or('.', Pipe::drawing));
+    let mut picture = Picture::new(DAY, cells);
+
+    for (position, pipe) in field.grid.iter() {
+        if pipe.is_some() {
+            picture.mark(position, Mark::Ignored);
+        }
+    }
+    for position in field.find_loop() {
+        picture.mark(position, Mark::Counted);
+    }
+    if let Some(start) = field.start_position {
+        picture.mark(start, Mark::Special);
+    }
+
+    picture.describe(Mark::Counted, "a tile of the loop");
+    picture.describe(Mark::Ignored, "a pipe that is not part of the loop");
+    picture.describe(Mark::Special, "the start");
+    Ok(picture)
+}
+
+pub fn day10a(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    let field: Field = input.parse()?;
     let path = field.find_loop();
     Ok(path.len() / 2)
 }
 
-#[pyfunction]
-pub fn day10b(file_path: &str) -> PyResult<usize> {
-    todo!()
+pub fn day10b(_input: &str) -> Result<usize, AocError> {
+    Err(AocError::not_solved(DAY, Part::B))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_from_str() {
+        let field: Field = ".....\n.S-7.\n.|.|.\n.L-J.\n.....".parse().unwrap();
+        assert_eq!(5, field.grid.width());
+        assert_eq!(Some((1, 1)), field.start_position);
+        assert_eq!(Some(&Pipe::BendSouthWest), field.pipe((3, 1)));
+        assert_eq!(None, field.pipe((0, 0)));
+    }
+
+    #[test]
+    fn square_loop() {
+        let field: Field = ".....\n.S-7.\n.|.|.\n.L-J.\n.....".parse().unwrap();
+        assert_eq!(8, field.find_loop().len());
+    }
+
+    #[test]
+    fn unknown_pipe() {
+        let error = ".S-7\n.|x|".parse::<Field>().unwrap_err();
+        assert!(matches!(
+            error,
+            AocError::Parse {
+                line: 2,
+                column: 3,
+                ..
+            }
+        ));
+    }
 }
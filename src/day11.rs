@@ -1,13 +1,94 @@
-use pyo3::prelude::*;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::error::AocError;
+use crate::grid::{Grid, Position};
+use crate::input;
+use crate::render::{Mark, Picture};
+use crate::solver::Part;
+use serde::Serialize;
 
-#[pyfunction]
-pub fn day11a(_file_path: &str) -> PyResult<usize> {
-    todo!()
+const DAY: u8 = 11;
+
+/// the galaxies in the image, by their position
+#[derive(Debug, Serialize)]
+pub struct Puzzle {
+    width: usize,
+    height: usize,
+    galaxies: Vec<Position>,
+}
+
+pub fn day11_parse(input: &str) -> Result<Puzzle, AocError> {
+    let input = &input::normalize(input);
+    let image: Grid<char> = input.parse()?;
+    let mut galaxies = Vec::new();
+
+    for ((x, y), ch) in image.iter() {
+        match ch {
+            '#' => galaxies.push((x, y)),
+            '.' => (),
+            _ => {
+                return Err(AocError::Parse {
+                    day: DAY,
+                    line: y + 1,
+                    column: x + 1,
+                    text: ch.to_string(),
+                    expected: String::from("'#' or '.'"),
+                })
+            }
+        }
+    }
+
+    Ok(Puzzle {
+        width: image.width(),
+        height: image.height(),
+        galaxies,
+    })
+}
+
+/// true where there is a galaxy
+pub fn day11_galaxies(input: &str) -> Result<Grid<bool>, AocError> {
+    let input = &input::normalize(input);
+    let puzzle = day11_parse(input)?;
+    let mut grid = Grid::filled(puzzle.width, puzzle.height, false);
+    for galaxy in puzzle.galaxies {
+        grid[galaxy] = true;
+    }
+
+    Ok(grid)
+}
+
+/// the galaxies and the rows and columns without one, those expand
+pub fn day11_render(input: &str) -> Result<Picture, AocError> {
+    let input = &input::normalize(input);
+    let puzzle = day11_parse(input)?;
+    let mut cells = Grid::filled(puzzle.width, puzzle.height, '.');
+    let mut empty_rows = vec![true; puzzle.height];
+    let mut empty_columns = vec![true; puzzle.width];
+    for &(x, y) in &puzzle.galaxies {
+        cells[(x, y)] = '#';
+        empty_rows[y] = false;
+        empty_columns[x] = false;
+    }
+
+    let mut picture = Picture::new(DAY, cells);
+    for (y, empty_row) in empty_rows.iter().enumerate() {
+        for (x, empty_column) in empty_columns.iter().enumerate() {
+            if *empty_row || *empty_column {
+                picture.mark((x, y), Mark::Space);
+            }
+        }
+    }
+    for &galaxy in &puzzle.galaxies {
+        picture.mark(galaxy, Mark::Special);
+    }
+
+    picture.describe(Mark::Special, "a galaxy");
+    picture.describe(Mark::Space, "a row or column without galaxies, it expands");
+    Ok(picture)
+}
+
+pub fn day11a(_input: &str) -> Result<usize, AocError> {
+    Err(AocError::not_solved(DAY, Part::A))
 }
 
-#[pyfunction]
-pub fn day11b(_file_path: &str) -> PyResult<usize> {
-    todo!()
+pub fn day11b(_input: &str) -> Result<usize, AocError> {
+    Err(AocError::not_solved(DAY, Part::B))
 }
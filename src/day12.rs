@@ -1,9 +1,14 @@
-use pyo3::exceptions::PyValueError;
-use pyo3::prelude::*;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::input;
+use crate::parse::{self, Parser};
+use crate::solver::Part;
+use serde::Serialize;
+use tracing::trace;
 
-#[derive(Debug, PartialEq)]
+const DAY: u8 = 12;
+
+#[derive(Debug, PartialEq, Serialize)]
 enum Spring {
     Damaged,
     Operational,
@@ -11,27 +16,43 @@ enum Spring {
 }
 
 impl TryFrom<char> for Spring {
-    type Error = PyErr;
+    type Error = ();
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         let ch = match value {
             '#' => Spring::Damaged,
             '.' => Spring::Operational,
             '?' => Spring::Unknown,
-            _ => return Err(PyErr::new::<PyValueError, _>("invalid char")),
+            _ => return Err(()),
         };
 
         Ok(ch)
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 struct Row {
     numbers: Vec<usize>,
     springs: Vec<Spring>,
 }
 
 impl Row {
+    fn parse(parser: &mut Parser<'_>) -> Result<Row, AocError> {
+        let text = parser.take_while(|ch| !ch.is_whitespace());
+        let springs = text
+            .char_indices()
+            .map(|(offset, ch)| {
+                Spring::try_from(ch).map_err(|_| {
+                    let spring = &text[offset..(offset + ch.len_utf8())];
+                    parser.error_at(spring, "'#', '.' or '?'")
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        let numbers = parser.separated(",", |parser| parser.number())?;
+
+        Ok(Row { numbers, springs })
+    }
+
     fn trim_operational_springs(&mut self) {
         while let Some(Spring::Operational) = self.springs.first() {
             self.springs.remove(0);
@@ -43,34 +64,71 @@ impl Row {
     }
 }
 
-#[pyfunction]
-pub fn day12a(file_path: &str) -> PyResult<usize> {
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
-
-    for line in reader.lines() {
-        let line = line?;
-        let (text, numbers_text) = line
-            .split_once(" ")
-            .ok_or_else(|| PyErr::new::<PyValueError, _>("invalid line"))?;
-        let numbers: Vec<usize> = numbers_text
-            .split(',')
-            .map(|x| x.parse())
-            .collect::<Result<_, _>>()?;
-        let springs: Vec<Spring> = text
-            .chars()
-            .map(Spring::try_from)
-            .collect::<Result<_, _>>()?;
+#[derive(Debug, Serialize)]
+pub struct Puzzle {
+    rows: Vec<Row>,
+}
+
+pub fn day12_parse(input: &str) -> Result<Puzzle, AocError> {
+    let input = &input::normalize(input);
+    Ok(Puzzle {
+        rows: parse::lines(DAY, input, Row::parse)?,
+    })
+}
+
+/// the springs of every row, 0 operational, 1 damaged and 2 unknown. the rows are padded with -1
+/// to the longest one
+pub fn day12_springs(input: &str) -> Result<Grid<i8>, AocError> {
+    let input = &input::normalize(input);
+    let rows = parse::lines(DAY, input, Row::parse)?;
+    let width = rows.iter().map(|row| row.springs.len()).max().unwrap_or(0);
 
-        let mut row = Row { numbers, springs };
+    let mut cells = Vec::with_capacity(width * rows.len());
+    for row in &rows {
+        cells.extend(row.springs.iter().map(|spring| match spring {
+            Spring::Operational => 0,
+            Spring::Damaged => 1,
+            Spring::Unknown => 2,
+        }));
+        cells.extend(std::iter::repeat_n(-1, width - row.springs.len()));
+    }
+
+    Ok(Grid::from_cells(width, rows.len(), cells).expect("every row is padded to the width"))
+}
+
+pub fn day12a(input: &str) -> Result<usize, AocError> {
+    let input = &input::normalize(input);
+    for mut row in parse::lines(DAY, input, Row::parse)? {
         row.trim_operational_springs();
-        dbg!(row);
+        trace!(?row, "trimmed row");
     }
 
-    todo!()
+    Err(AocError::not_solved(DAY, Part::A))
 }
 
-#[pyfunction]
-pub fn day12b(file_path: &str) -> PyResult<usize> {
-    todo!()
+pub fn day12b(_input: &str) -> Result<usize, AocError> {
+    Err(AocError::not_solved(DAY, Part::B))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spring_try_from() {
+        assert_eq!(Ok(Spring::Damaged), Spring::try_from('#'));
+        assert_eq!(Ok(Spring::Operational), Spring::try_from('.'));
+        assert_eq!(Ok(Spring::Unknown), Spring::try_from('?'));
+        assert_eq!(Err(()), Spring::try_from('x'));
+    }
+
+    #[test]
+    fn trim_operational_springs() {
+        let mut row = Row {
+            numbers: vec![1],
+            springs: vec![Spring::Operational, Spring::Damaged, Spring::Operational],
+        };
+        row.trim_operational_springs();
+        assert_eq!(vec![Spring::Damaged], row.springs);
+    }
 }
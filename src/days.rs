@@ -0,0 +1,71 @@
+// generated by `aoc2023 new-day` from the `src/dayNN.rs` files. swap `unsolved!` for `solver!`
+// once a part is solved, other changes are overwritten by the next new day
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+
+mod registry {
+    use crate::error::AocError;
+    use crate::model::to_value;
+    use crate::solver::{solver, unsolved, Solver};
+    use serde_json::Value;
+
+    /// the days with a module
+    pub(crate) const DAYS: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+    pub(crate) static SOLVERS: &[&dyn Solver] = &[
+        solver!(1, A, day01::day01a),
+        solver!(1, B, day01::day01b),
+        solver!(2, A, day02::day02a),
+        solver!(2, B, day02::day02b),
+        solver!(3, A, day03::day03a),
+        solver!(3, B, day03::day03b),
+        solver!(4, A, day04::day04a),
+        solver!(4, B, day04::day04b),
+        solver!(5, A, day05::day05a),
+        solver!(5, B, day05::day05b),
+        solver!(6, A, day06::day06a),
+        solver!(6, B, day06::day06b),
+        solver!(7, A, day07::day07a),
+        unsolved!(7, B, day07::day07b),
+        solver!(8, A, day08::day08a),
+        solver!(8, B, day08::day08b),
+        solver!(9, A, day09::day09a),
+        solver!(9, B, day09::day09b),
+        solver!(10, A, day10::day10a),
+        unsolved!(10, B, day10::day10b),
+        unsolved!(11, A, day11::day11a),
+        unsolved!(11, B, day11::day11b),
+        unsolved!(12, A, day12::day12a),
+        unsolved!(12, B, day12::day12b),
+    ];
+
+    /// parses `input` as the puzzle of `day`
+    pub(crate) fn parse(day: u8, input: &str) -> Result<Value, AocError> {
+        match day {
+            1 => to_value(day, crate::day01::day01_parse(input)),
+            2 => to_value(day, crate::day02::day02_parse(input)),
+            3 => to_value(day, crate::day03::day03_parse(input)),
+            4 => to_value(day, crate::day04::day04_parse(input)),
+            5 => to_value(day, crate::day05::day05_parse(input)),
+            6 => to_value(day, crate::day06::day06_parse(input)),
+            7 => to_value(day, crate::day07::day07_parse(input)),
+            8 => to_value(day, crate::day08::day08_parse(input)),
+            9 => to_value(day, crate::day09::day09_parse(input)),
+            10 => to_value(day, crate::day10::day10_parse(input)),
+            11 => to_value(day, crate::day11::day11_parse(input)),
+            12 => to_value(day, crate::day12::day12_parse(input)),
+            _ => Err(AocError::Input(format!("no parser for day {}", day))),
+        }
+    }
+}
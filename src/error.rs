@@ -0,0 +1,242 @@
+use crate::solver::Part;
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::Once;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AocError {
+    /// `line` and `column` are 1 based, a `line` of 0 means the line is not known (yet)
+    Parse {
+        day: u8,
+        line: usize,
+        column: usize,
+        text: String,
+        expected: String,
+    },
+    Input(String),
+    Unsolvable {
+        day: u8,
+        message: String,
+    },
+    /// the part has no solution yet
+    NotSolved {
+        day: u8,
+        part: Part,
+    },
+    /// a bug, see [`catch_panic`]
+    Panicked {
+        day: u8,
+        message: String,
+    },
+}
+
+impl AocError {
+    /// `found` should be a slice of `line`, so the column can be calculated
+    pub fn parse(day: u8, line: &str, found: &str, expected: &str) -> AocError {
+        AocError::Parse {
+            day,
+            line: 0,
+            column: column_of(line, found),
+            text: found.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    pub fn unsolvable(day: u8, message: &str) -> AocError {
+        AocError::Unsolvable {
+            day,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn not_solved(day: u8, part: Part) -> AocError {
+        AocError::NotSolved { day, part }
+    }
+
+    /// sets the line number if it is not set yet
+    pub fn at_line(mut self, line_number: usize) -> AocError {
+        if let AocError::Parse { line, .. } = &mut self {
+            if *line == 0 {
+                *line = line_number;
+            }
+        }
+        self
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Parse {
+                day,
+                line,
+                column,
+                text,
+                expected,
+            } => write!(
+                f,
+                "day {:02}, line {}, column {}: expected {} but found {:?}",
+                day, line, column, expected, text
+            ),
+            AocError::Input(message) => write!(f, "invalid input: {}", message),
+            AocError::Unsolvable { day, message } => write!(f, "day {:02}: {}", day, message),
+            AocError::NotSolved { day, part } => {
+                write!(f, "day {:02} part {} is not solved yet", day, part)
+            }
+            AocError::Panicked { day, message } => {
+                write!(f, "day {:02}: panicked: {}", day, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<std::io::Error> for AocError {
+    fn from(error: std::io::Error) -> Self {
+        AocError::Input(error.to_string())
+    }
+}
+
+/// 1 based column of `part` inside `line`, 1 if `part` is not a slice of `line`
+pub fn column_of(line: &str, part: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let position = part.as_ptr() as usize;
+
+    if position >= start && position <= start + line.len() {
+        position - start + 1
+    } else {
+        1
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+thread_local! {
+    /// how many [`catch_panic`] calls the thread is inside of
+    static CATCHING: Cell<usize> = const { Cell::new(0) };
+}
+
+/// wraps the panic hook once, a panic that [`catch_panic`] turns into an error is not
+/// printed to stderr as well. panics outside of it still go to the previous hook
+fn install_quiet_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(|catching| catching.get()) == 0 {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// runs `f`, if it panics the panic becomes an [`AocError::Panicked`] of `day`.
+/// the bindings use this so a bug in one solver does not take down the caller
+pub fn catch_panic<T>(day: u8, f: impl FnOnce() -> Result<T, AocError>) -> Result<T, AocError> {
+    install_quiet_hook();
+
+    CATCHING.with(|catching| catching.set(catching.get() + 1));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(catching.get() - 1));
+
+    result.unwrap_or_else(|payload| {
+        Err(AocError::Panicked {
+            day,
+            message: panic_message(payload.as_ref()),
+        })
+    })
+}
+
+/// the result of checked arithmetic, `None` means the numbers of the input are too large
+pub fn checked<T>(day: u8, value: Option<T>) -> Result<T, AocError> {
+    value.ok_or_else(|| AocError::unsolvable(day, "the numbers are too large"))
+}
+
+/// like [`Iterator::sum`], but an overflow is an error instead of a panic
+pub fn checked_sum(day: u8, items: impl IntoIterator<Item = usize>) -> Result<usize, AocError> {
+    items
+        .into_iter()
+        .try_fold(0usize, |sum, item| checked(day, sum.checked_add(item)))
+}
+
+pub fn parse_number<T: FromStr>(day: u8, line: &str, text: &str) -> Result<T, AocError> {
+    text.parse()
+        .map_err(|_| AocError::parse(day, line, text, "a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_of_slice() {
+        let line = "Game 1: 3 blue";
+        assert_eq!(1, column_of(line, line));
+        assert_eq!(9, column_of(line, &line[8..]));
+        assert_eq!(1, column_of(line, "blue"));
+    }
+
+    #[test]
+    fn at_line_keeps_first_line() {
+        let error = AocError::parse(1, "x", "x", "a digit")
+            .at_line(3)
+            .at_line(5);
+        assert!(matches!(error, AocError::Parse { line: 3, .. }));
+    }
+
+    #[test]
+    fn catch_panic_message() {
+        let result: Result<(), _> = catch_panic(3, || panic!("index {} out of bounds", 5));
+        assert_eq!(
+            Err(AocError::Panicked {
+                day: 3,
+                message: String::from("index 5 out of bounds")
+            }),
+            result
+        );
+        assert_eq!(0, CATCHING.with(|catching| catching.get()));
+    }
+
+    #[test]
+    fn catch_panic_nested() {
+        let result = catch_panic(3, || {
+            let inner: Result<(), _> = catch_panic(4, || panic!("inner"));
+            assert_eq!(1, CATCHING.with(|catching| catching.get()));
+            inner
+        });
+        assert!(matches!(result, Err(AocError::Panicked { day: 4, .. })));
+        assert_eq!(0, CATCHING.with(|catching| catching.get()));
+    }
+
+    #[test]
+    fn checked_overflow() {
+        assert_eq!(Ok(3), checked_sum(4, [1, 2]));
+        assert_eq!(
+            Err(AocError::unsolvable(4, "the numbers are too large")),
+            checked_sum(4, [usize::MAX, 1])
+        );
+    }
+
+    #[test]
+    fn display() {
+        let line = "1 rad";
+        let error = AocError::parse(2, line, &line[2..], "'red'").at_line(4);
+        assert_eq!(
+            "day 02, line 4, column 3: expected 'red' but found \"rad\"",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! Explains an answer line by line, for the days where the answer is a sum over the lines.
+//!
+//! Every line gets an [`Explanation`] with what it parsed to, what it adds to the answer and the
+//! steps in between. The contributions add up to the answer, so comparing them with another
+//! implementation points at the first line that differs.
+
+use crate::error::AocError;
+use crate::solver::{self, Answer, Part};
+use crate::{day01, day02, day04, day07, day09};
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Explanation {
+    /// 1 based, like the lines of the errors
+    pub line: usize,
+    pub text: String,
+    pub parsed: Value,
+    pub contribution: Answer,
+    /// the steps between `parsed` and `contribution`, these differ per day
+    pub details: Value,
+}
+
+impl Explanation {
+    pub fn new(
+        line: usize,
+        text: &str,
+        parsed: &impl Serialize,
+        contribution: impl Into<Answer>,
+        details: Value,
+    ) -> Explanation {
+        Explanation {
+            line,
+            text: text.to_string(),
+            parsed: serde_json::to_value(parsed).expect("a parsed line is valid json"),
+            contribution: contribution.into(),
+            details,
+        }
+    }
+}
+
+/// the parts that can be explained
+pub const EXPLAINED: &[(u8, Part)] = &[
+    (1, Part::A),
+    (1, Part::B),
+    (2, Part::A),
+    (2, Part::B),
+    (4, Part::A),
+    (4, Part::B),
+    (7, Part::A),
+    (9, Part::A),
+    (9, Part::B),
+];
+
+/// the lines of `input` that add to the answer of `part` of `day`, in the order of the input
+pub fn explain(day: u8, part: Part, input: &str) -> Result<Vec<Explanation>, AocError> {
+    match (day, part) {
+        (1, Part::A) => day01::day01a_explain(input),
+        (1, Part::B) => day01::day01b_explain(input),
+        (2, Part::A) => day02::day02a_explain(input),
+        (2, Part::B) => day02::day02b_explain(input),
+        (4, Part::A) => day04::day04a_explain(input),
+        (4, Part::B) => day04::day04b_explain(input),
+        (7, Part::A) => day07::day07a_explain(input),
+        (9, Part::A) => day09::day09a_explain(input),
+        (9, Part::B) => day09::day09b_explain(input),
+        _ => match solver::find(day, part) {
+            Some(solver) if !solver.solved() => Err(AocError::not_solved(day, part)),
+            _ => Err(AocError::Input(format!(
+                "day {} part {} is not explained line by line",
+                day, part
+            ))),
+        },
+    }
+}
+
+/// the sum of the contributions, the answer of the part
+pub fn total(explanations: &[Explanation]) -> i128 {
+    explanations
+        .iter()
+        .map(|explanation| explanation.contribution.as_i128())
+        .sum()
+}
+
+pub fn to_json(explanations: &[Explanation]) -> String {
+    serde_json::to_string_pretty(explanations).expect("explanations only contain plain data")
+}
+
+/// a row per line and the total, `parsed` is left out
+pub fn to_markdown(explanations: &[Explanation]) -> String {
+    let mut table = String::from(
+        "| line | text | contribution | details |\n\
+         |-----:|:-----|-------------:|:--------|\n",
+    );
+
+    for explanation in explanations {
+        writeln!(
+            table,
+            "| {} | `{}` | {} | `{}` |",
+            explanation.line, explanation.text, explanation.contribution, explanation.details
+        )
+        .expect("writing to a string does not fail");
+    }
+    writeln!(table, "| | total | {} | |", total(explanations))
+        .expect("writing to a string does not fail");
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contributions_add_up_to_the_answer() {
+        for &(day, part) in EXPLAINED {
+            let input = std::fs::read_to_string(format!("data/day{:02}_debug.txt", day)).unwrap();
+            let answer = solver::find(day, part).unwrap().solve(&input);
+            let explanations = explain(day, part, &input);
+
+            // the sample of day 1 is the one of part b, part a does not solve it
+            assert_eq!(
+                answer.map(Answer::as_i128),
+                explanations.map(|explanations| total(&explanations)),
+                "day {} part {}",
+                day,
+                part
+            );
+        }
+    }
+
+    #[test]
+    fn day01b_lines() {
+        let error = explain(1, Part::B, "two1nine\n\nxtwone3four").unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: 2, .. }));
+
+        let explanations = explain(1, Part::B, "two1nine\nxtwone3four").unwrap();
+        assert_eq!(2, explanations[1].line);
+        assert_eq!("xtwone3four", explanations[1].text);
+        assert_eq!(Answer::Unsigned(24), explanations[1].contribution);
+    }
+
+    #[test]
+    fn day07a_ranks_in_input_order() {
+        let explanations = explain(7, Part::A, "KK677 28\n32T3K 765\nT55J5 684").unwrap();
+        let ranks: Vec<_> = explanations
+            .iter()
+            .map(|explanation| explanation.details["rank"].as_u64().unwrap())
+            .collect();
+        assert_eq!(vec![2, 1, 3], ranks);
+        assert_eq!(Answer::Unsigned(765), explanations[1].contribution);
+    }
+
+    #[test]
+    fn markdown() {
+        let explanations = explain(9, Part::B, "10 13 16 21 30 45\n0 3 6").unwrap();
+        assert_eq!(
+            "| line | text | contribution | details |\n\
+             |-----:|:-----|-------------:|:--------|\n\
+             | 1 | `10 13 16 21 30 45` | 5 | `{\"differences\":[[10,13,16,21,30,45],[3,3,5,9,15],[0,2,4,6],[2,2,2],[0,0]]}` |\n\
+             | 2 | `0 3 6` | -3 | `{\"differences\":[[0,3,6],[3,3],[0]]}` |\n\
+             | | total | 2 | |\n",
+            to_markdown(&explanations)
+        );
+    }
+
+    #[test]
+    fn unexplained_parts() {
+        assert_eq!(
+            Err(AocError::not_solved(7, Part::B)),
+            explain(7, Part::B, "")
+        );
+        assert!(matches!(explain(5, Part::A, ""), Err(AocError::Input(_))));
+    }
+}
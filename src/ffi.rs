@@ -0,0 +1,250 @@
+//! C bindings, for the dart package and anything else that can load a shared library.
+//!
+//! The input goes in as a pointer to utf-8 bytes and a length. Every function returns an
+//! [`AocResult`], its text is the answer, JSON or the error message. The caller owns the text and
+//! gives it back with [`aoc_result_free`]. The header is generated from this module by cbindgen,
+//! `aoc2023 header > include/aoc2023.h`, a test checks the checked in header is up to date.
+
+use crate::error::{catch_panic, AocError};
+use crate::input::Input;
+use crate::model;
+use crate::solver::{self, Part};
+use serde_json::json;
+use std::{ptr, slice};
+
+pub const HEADER_PATH: &str = "include/aoc2023.h";
+
+/// the `status` of an [`AocResult`] with an answer
+pub const AOC_OK: i32 = 0;
+pub const AOC_PARSE_ERROR: i32 = 1;
+pub const AOC_INPUT_ERROR: i32 = 2;
+pub const AOC_UNSOLVABLE: i32 = 3;
+pub const AOC_NOT_SOLVED: i32 = 4;
+pub const AOC_PANIC: i32 = 5;
+
+/// `status` is [`AOC_OK`] or one of the errors, `text` is `length` bytes of utf-8 without a
+/// trailing 0
+#[repr(C)]
+#[derive(Debug)]
+pub struct AocResult {
+    pub status: i32,
+    pub text: *mut u8,
+    pub length: usize,
+}
+
+impl AocResult {
+    fn new(status: i32, text: String) -> AocResult {
+        let length = text.len();
+        let text = Box::into_raw(text.into_bytes().into_boxed_slice()) as *mut u8;
+
+        AocResult {
+            status,
+            text,
+            length,
+        }
+    }
+}
+
+impl From<Result<String, AocError>> for AocResult {
+    fn from(result: Result<String, AocError>) -> Self {
+        match result {
+            Ok(text) => AocResult::new(AOC_OK, text),
+            Err(error) => {
+                let status = match error {
+                    AocError::Parse { .. } => AOC_PARSE_ERROR,
+                    AocError::Input(_) => AOC_INPUT_ERROR,
+                    AocError::Unsolvable { .. } => AOC_UNSOLVABLE,
+                    AocError::NotSolved { .. } => AOC_NOT_SOLVED,
+                    AocError::Panicked { .. } => AOC_PANIC,
+                };
+                AocResult::new(status, error.to_string())
+            }
+        }
+    }
+}
+
+/// # Safety
+/// `input` points to `length` readable bytes, it may be null when `length` is 0
+unsafe fn read_input(input: *const u8, length: usize) -> Result<String, AocError> {
+    let bytes = if input.is_null() {
+        if length != 0 {
+            return Err(AocError::Input(String::from("input is null")));
+        }
+        &[]
+    } else {
+        slice::from_raw_parts(input, length)
+    };
+
+    Input::Bytes(bytes.to_vec()).read()
+}
+
+/// solves `part`, `'a'` or `'b'`, of `day`. the text is the answer as a decimal number
+///
+/// # Safety
+/// `input` points to `length` readable bytes, it may be null when `length` is 0
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    length: usize,
+) -> AocResult {
+    let input = read_input(input, length);
+    let result = input.and_then(|input| {
+        let part: Part = char::from(part).to_string().parse()?;
+        let solver = solver::find(day, part)
+            .ok_or_else(|| AocError::Input(format!("no solver for day {} part {}", day, part)))?;
+        solver::run(solver, &input).result
+    });
+
+    AocResult::from(result.map(|answer| answer.to_string()))
+}
+
+/// the parsed puzzle of `day` as JSON, like `aoc2023 parse`
+///
+/// # Safety
+/// `input` points to `length` readable bytes, it may be null when `length` is 0
+#[no_mangle]
+pub unsafe extern "C" fn aoc_parse(day: u8, input: *const u8, length: usize) -> AocResult {
+    let input = read_input(input, length);
+    let result = input.and_then(|input| catch_panic(day, || model::to_json(day, &input)));
+
+    AocResult::from(result)
+}
+
+/// the solvers as a JSON list of `{"day": 1, "part": "a", "name": "day01a", "solved": true}`
+#[no_mangle]
+pub extern "C" fn aoc_solvers() -> AocResult {
+    let solvers: Vec<_> = solver::solvers()
+        .iter()
+        .map(|solver| {
+            json!({
+                "day": solver.day(),
+                "part": solver.part(),
+                "name": solver.name(),
+                "solved": solver.solved(),
+            })
+        })
+        .collect();
+
+    AocResult::from(Ok(json!(solvers).to_string()))
+}
+
+/// frees the text of a result, every result should be freed once
+///
+/// # Safety
+/// `result` is returned by one of the functions above and is not freed yet
+#[no_mangle]
+pub unsafe extern "C" fn aoc_result_free(result: AocResult) {
+    if !result.text.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+            result.text,
+            result.length,
+        )));
+    }
+}
+
+/// the header of the functions, structs and constants above, made by cbindgen from this file
+#[cfg(feature = "cli")]
+pub fn c_header() -> String {
+    use cbindgen::{Builder, Config, DocumentationStyle, ExportConfig, Language, Style};
+    use std::fmt::Write;
+
+    let mut solvers = String::from("\n/* the solvers, `day` and `part` of aoc_solve:\n");
+    for solver in solver::solvers() {
+        let solved = if solver.solved() {
+            ""
+        } else {
+            " (not solved yet)"
+        };
+        writeln!(
+            solvers,
+            " * {} {} {}{}",
+            solver.day(),
+            solver.part(),
+            solver.name(),
+            solved
+        )
+        .expect("writing to a string does not fail");
+    }
+    solvers.push_str(" */");
+
+    // the fields of `Config` are set one by one, it has a private field
+    let mut config = Config::default();
+    config.language = Language::C;
+    config.header = Some(String::from(
+        "/* generated by `aoc2023 header`, do not edit */",
+    ));
+    config.include_guard = Some(String::from("AOC2023_H"));
+    config.no_includes = true;
+    config.sys_includes = vec![String::from("stddef.h"), String::from("stdint.h")];
+    config.cpp_compat = true;
+    config.style = Style::Both;
+    config.usize_is_size_t = true;
+    config.documentation_style = DocumentationStyle::Doxy;
+    config.after_includes = Some(solvers);
+    config.export = ExportConfig {
+        exclude: vec![String::from("HEADER_PATH")],
+        ..ExportConfig::default()
+    };
+
+    let mut header = Vec::new();
+    Builder::new()
+        .with_config(config)
+        .with_src(concat!(env!("CARGO_MANIFEST_DIR"), "/src/ffi.rs"))
+        .generate()
+        .expect("cbindgen understands the ffi module")
+        .write(&mut header);
+    String::from_utf8(header).expect("the header is utf-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(result: AocResult) -> (i32, String) {
+        let text = unsafe { slice::from_raw_parts(result.text, result.length) };
+        let text = (result.status, String::from_utf8(text.to_vec()).unwrap());
+        unsafe { aoc_result_free(result) };
+        text
+    }
+
+    #[test]
+    fn solve() {
+        let input = "10 13 16 21 30 45";
+        let result = unsafe { aoc_solve(9, b'b', input.as_ptr(), input.len()) };
+        assert_eq!((AOC_OK, String::from("5")), text(result));
+    }
+
+    #[test]
+    fn solve_errors() {
+        let result = unsafe { aoc_solve(11, b'a', ptr::null(), 0) };
+        assert_eq!(AOC_NOT_SOLVED, text(result).0);
+
+        let result = unsafe { aoc_solve(1, b'c', ptr::null(), 0) };
+        assert_eq!(AOC_INPUT_ERROR, text(result).0);
+
+        let input = [b'1', 0xff];
+        let result = unsafe { aoc_solve(1, b'a', input.as_ptr(), input.len()) };
+        assert_eq!(AOC_INPUT_ERROR, text(result).0);
+    }
+
+    #[test]
+    fn parse() {
+        let input = "LR\n\nAAA = (BBB CCC)";
+        let (status, message) = text(unsafe { aoc_parse(8, input.as_ptr(), input.len()) });
+        assert_eq!(AOC_PARSE_ERROR, status);
+        assert!(message.starts_with("day 08, line 3"), "{}", message);
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn checked_in_header_is_up_to_date() {
+        assert_eq!(
+            include_str!("../include/aoc2023.h"),
+            c_header(),
+            "run `just header` to regenerate {}",
+            HEADER_PATH
+        );
+    }
+}
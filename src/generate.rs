@@ -0,0 +1,485 @@
+//! Seeded random puzzle inputs, to stress the solvers and to compare them with brute force.
+//!
+//! The same `(day, seed, size)` always gives the same input. `size` is the amount of lines, or
+//! about the width of the picture for the days that are a grid.
+
+use crate::error::AocError;
+use crate::grid::{Direction, Grid, Position};
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::{BTreeSet, HashSet};
+use std::ops::RangeInclusive;
+
+/// the days that have a generator
+pub const DAYS: RangeInclusive<u8> = 1..=12;
+
+pub fn generate(day: u8, seed: u64, size: usize) -> Result<String, AocError> {
+    if size == 0 {
+        return Err(AocError::Input(String::from("size should be at least 1")));
+    }
+
+    let rng = &mut ChaCha8Rng::seed_from_u64(seed);
+    let lines = match day {
+        1 => calibration_document(rng, size),
+        2 => game_records(rng, size),
+        3 => schematic(rng, size),
+        4 => scratchcards(rng, size),
+        5 => almanac(rng, size),
+        6 => races(rng, size),
+        7 => camel_cards(rng, size),
+        8 => network(rng, size),
+        9 => histories(rng, size),
+        10 => pipe_maze(rng, size).0,
+        11 => galaxy_image(rng, size),
+        12 => spring_rows(rng, size),
+        _ => return Err(AocError::Input(format!("no generator for day {}", day))),
+    };
+
+    Ok(lines.into_iter().map(|line| line + "\n").collect())
+}
+
+fn join<T: ToString>(items: impl IntoIterator<Item = T>, separator: &str) -> String {
+    items
+        .into_iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// letters, digits and spelled out digits, every line has at least one real digit
+fn calibration_document(rng: &mut ChaCha8Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let pieces = rng.gen_range(2..=6);
+            let digit_piece = rng.gen_range(0..pieces);
+            let mut line = String::new();
+
+            for piece in 0..pieces {
+                match rng.gen_range(0..3) {
+                    _ if piece == digit_piece => line.push_str(&rng.gen_range(1..=9).to_string()),
+                    0 => line.push_str(DIGIT_WORDS.choose(rng).expect("not empty")),
+                    1 => line.push_str(&rng.gen_range(1..=9).to_string()),
+                    _ => line.extend((0..rng.gen_range(1..=4)).map(|_| rng.gen_range('a'..='z'))),
+                }
+            }
+
+            line
+        })
+        .collect()
+}
+
+fn game_records(rng: &mut ChaCha8Rng, size: usize) -> Vec<String> {
+    (1..=size)
+        .map(|id| {
+            let hands = (0..rng.gen_range(1..=6)).map(|_| {
+                let mut colours = ["red", "green", "blue"];
+                colours.shuffle(rng);
+                let amount = rng.gen_range(1..=3);
+                join(
+                    colours[..amount]
+                        .iter()
+                        .map(|colour| format!("{} {}", rng.gen_range(1..=20), colour)),
+                    ", ",
+                )
+            });
+            format!("Game {}: {}", id, join(hands.collect::<Vec<_>>(), "; "))
+        })
+        .collect()
+}
+
+const SYMBOLS: &[char] = &['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+/// `size` by `size`, numbers always have a `.` after them so they do not run into each other
+fn schematic(rng: &mut ChaCha8Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let mut row = String::new();
+            while row.len() < size {
+                match rng.gen_range(0..10) {
+                    0 | 1 if row.len() + 4 <= size => {
+                        row.push_str(&rng.gen_range(1..1000).to_string());
+                        row.push('.');
+                    }
+                    2 => row.push(*SYMBOLS.choose(rng).expect("not empty")),
+                    _ => row.push('.'),
+                }
+            }
+            row
+        })
+        .collect()
+}
+
+fn scratchcards(rng: &mut ChaCha8Rng, size: usize) -> Vec<String> {
+    let numbers: Vec<usize> = (1..100).collect();
+    let pick = |rng: &mut ChaCha8Rng, amount| {
+        join(
+            numbers
+                .choose_multiple(rng, amount)
+                .map(|number| format!("{:>2}", number)),
+            " ",
+        )
+    };
+
+    (1..=size)
+        .map(|card| {
+            let winning = pick(rng, 10);
+            let ours = pick(rng, 25);
+            format!("Card {:>3}: {} | {}", card, winning, ours)
+        })
+        .collect()
+}
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// `size` seed ranges and `size` entries per map, the sources of a map do not overlap
+fn almanac(rng: &mut ChaCha8Rng, size: usize) -> Vec<String> {
+    const SPACE: usize = 2_000_000;
+
+    let seeds = (0..size).flat_map(|_| [rng.gen_range(0..SPACE), rng.gen_range(1..10_000)]);
+    let mut lines = vec![format!("seeds: {}", join(seeds.collect::<Vec<_>>(), " "))];
+
+    for categories in CATEGORIES.windows(2) {
+        lines.push(String::new());
+        lines.push(format!("{}-to-{} map:", categories[0], categories[1]));
+
+        let mut cuts = BTreeSet::new();
+        while cuts.len() < 2 * size {
+            cuts.insert(rng.gen_range(0..SPACE));
+        }
+        let cuts: Vec<usize> = cuts.into_iter().collect();
+        for source in cuts.chunks(2) {
+            let destination = rng.gen_range(0..SPACE);
+            lines.push(format!(
+                "{} {} {}",
+                destination,
+                source[0],
+                source[1] - source[0]
+            ));
+        }
+    }
+
+    lines
+}
+
+/// at most 4 races, part two glues the numbers together and more would not fit in an `i64`
+fn races(rng: &mut ChaCha8Rng, size: usize) -> Vec<String> {
+    let races: Vec<(u64, u64)> = (0..size.min(4))
+        .map(|_| {
+            let time = rng.gen_range(7..100);
+            // every race can be won, the record is below the best distance
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.gen_range(0..best))
+        })
+        .collect();
+
+    vec![
+        format!(
+            "Time:    {}",
+            join(races.iter().map(|(time, _)| format!("{:>5}", time)), "")
+        ),
+        format!(
+            "Distance:{}",
+            join(
+                races.iter().map(|(_, distance)| format!("{:>5}", distance)),
+                ""
+            )
+        ),
+    ]
+}
+
+const CARDS: &[char] = &[
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+fn camel_cards(rng: &mut ChaCha8Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let hand: String = (0..5)
+                .map(|_| *CARDS.choose(rng).expect("not empty"))
+                .collect();
+            format!("{} {}", hand, rng.gen_range(1..=1000))
+        })
+        .collect()
+}
+
+/// `size` nodes, every node only points to nodes after it so `AAA` always reaches `ZZZ`
+fn network(rng: &mut ChaCha8Rng, size: usize) -> Vec<String> {
+    let amount = size.clamp(2, 26 * 26 * 26);
+    let mut names = vec![String::from("AAA")];
+    let mut seen: HashSet<String> = ["AAA", "ZZZ"].iter().map(|name| name.to_string()).collect();
+    while names.len() < amount - 1 {
+        let name: String = (0..3).map(|_| rng.gen_range('A'..='Z')).collect();
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names.push(String::from("ZZZ"));
+
+    let mut nodes: Vec<String> = (0..amount)
+        .map(|index| {
+            let (left, right) = if index + 1 == amount {
+                (index, index)
+            } else {
+                (
+                    rng.gen_range((index + 1)..amount),
+                    rng.gen_range((index + 1)..amount),
+                )
+            };
+            format!("{} = ({}, {})", names[index], names[left], names[right])
+        })
+        .collect();
+    nodes.shuffle(rng);
+
+    let instructions = (0..rng.gen_range(2..=20))
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+    let mut lines = vec![instructions, String::new()];
+    lines.extend(nodes);
+    lines
+}
+
+/// 21 values of a polynomial of at most degree 4, so the differences always end in zeroes
+fn histories(rng: &mut ChaCha8Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let coefficients: Vec<i64> = (0..=rng.gen_range(0..=4))
+                .map(|_| rng.gen_range(-5..=5))
+                .collect();
+            let values = (0..21i64).map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |value, coefficient| value * x + coefficient)
+            });
+            join(values, " ")
+        })
+        .collect()
+}
+
+/// a random spanning tree with 4 cells between the nodes, the loop goes around it
+///
+/// the cells that touch the tree, diagonals included, form a single loop because the branches
+/// of the tree are 3 cells apart. Returns the maze and the length of the loop.
+fn pipe_maze(rng: &mut ChaCha8Rng, size: usize) -> (Vec<String>, usize) {
+    let nodes = (size / 4).max(1);
+    let width = 4 * nodes - 1;
+    let node_position = |(x, y): Position| (4 * x + 1, 4 * y + 1);
+
+    let mut tree = Grid::filled(width, width, false);
+    let mut visited = Grid::filled(nodes, nodes, false);
+    let start = (rng.gen_range(0..nodes), rng.gen_range(0..nodes));
+    let mut stack = vec![start];
+    visited[start] = true;
+    tree[node_position(start)] = true;
+
+    while let Some(&current) = stack.last() {
+        let unvisited: Vec<Position> = visited
+            .neighbours4(current)
+            .filter(|next| !visited[*next])
+            .collect();
+        let Some(&next) = unvisited.choose(rng) else {
+            stack.pop();
+            continue;
+        };
+
+        let (from, to) = (node_position(current), node_position(next));
+        for x in from.0.min(to.0)..=from.0.max(to.0) {
+            for y in from.1.min(to.1)..=from.1.max(to.1) {
+                tree[(x, y)] = true;
+            }
+        }
+        visited[next] = true;
+        stack.push(next);
+    }
+
+    let cells = tree
+        .iter()
+        .map(|(position, in_tree)| !in_tree && tree.neighbours8(position).any(|next| tree[next]))
+        .collect();
+    let on_loop = Grid::from_cells(width, width, cells).expect("same size as the tree");
+    let loop_cells: Vec<Position> = on_loop.positions().filter(|p| on_loop[*p]).collect();
+    let start = *loop_cells.choose(rng).expect("there is always a loop");
+
+    let mut maze = Grid::filled(width, width, '.');
+    for position in maze.positions() {
+        let next_to_start = on_loop.neighbours4(start).any(|next| next == position);
+        maze[position] = if on_loop[position] {
+            let opens: Vec<Direction> = Direction::ALL
+                .into_iter()
+                .filter(|direction| {
+                    on_loop
+                        .step(position, *direction)
+                        .is_some_and(|next| on_loop[next])
+                })
+                .collect();
+            pipe(&opens)
+        } else if !next_to_start && rng.gen_bool(0.5) {
+            // junk that is not part of the loop
+            *['|', '-', 'L', 'J', '7', 'F']
+                .choose(rng)
+                .expect("not empty")
+        } else {
+            '.'
+        };
+    }
+    maze[start] = 'S';
+
+    (
+        maze.rows().map(|row| row.iter().collect()).collect(),
+        loop_cells.len(),
+    )
+}
+
+/// the pipe that opens to both `directions`
+fn pipe(directions: &[Direction]) -> char {
+    use Direction::*;
+
+    match directions {
+        [Up, Down] => '|',
+        [Left, Right] => '-',
+        [Up, Right] => 'L',
+        [Up, Left] => 'J',
+        [Down, Left] => '7',
+        [Down, Right] => 'F',
+        _ => unreachable!("loop cells always have two neighbours on the loop"),
+    }
+}
+
+fn galaxy_image(rng: &mut ChaCha8Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.gen_bool(0.1) { '#' } else { '.' })
+                .collect()
+        })
+        .collect()
+}
+
+/// the damaged groups come from a real row, so there is always at least one arrangement
+fn spring_rows(rng: &mut ChaCha8Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let length = rng.gen_range(3..=20);
+            let mut springs: Vec<char> = (0..length)
+                .map(|_| if rng.gen_bool(0.4) { '#' } else { '.' })
+                .collect();
+            let damaged = rng.gen_range(0..length);
+            springs[damaged] = '#';
+
+            let springs: String = springs.into_iter().collect();
+            let groups = springs
+                .split('.')
+                .filter(|group| !group.is_empty())
+                .map(str::len);
+            let groups = join(groups.collect::<Vec<_>>(), ",");
+
+            let masked: String = springs
+                .chars()
+                .map(|spring| if rng.gen_bool(0.5) { '?' } else { spring })
+                .collect();
+            format!("{} {}", masked, groups)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{day06, day10, model, solver};
+
+    #[test]
+    fn same_seed_same_input() {
+        for day in DAYS {
+            assert_eq!(generate(day, 7, 10), generate(day, 7, 10));
+            assert_ne!(generate(day, 7, 10), generate(day, 8, 10), "day {}", day);
+        }
+    }
+
+    #[test]
+    fn every_day_parses() {
+        for day in DAYS {
+            for seed in 0..20 {
+                let input = generate(day, seed, 12).unwrap();
+                assert!(
+                    model::parse(day, &input).is_ok(),
+                    "day {} seed {}:\n{}",
+                    day,
+                    seed,
+                    input
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn solved_parts_run() {
+        for solver in solver::solvers().iter().filter(|solver| solver.solved()) {
+            for seed in 0..5 {
+                let input = generate(solver.day(), seed, 12).unwrap();
+                assert!(
+                    solver.solve(&input).is_ok(),
+                    "{} seed {}",
+                    solver.name(),
+                    seed
+                );
+            }
+        }
+    }
+
+    /// tries every button press
+    fn ways_to_win(time: u64, distance: u64) -> i32 {
+        (0..=time)
+            .filter(|hold| hold * (time - hold) > distance)
+            .count() as i32
+    }
+
+    #[test]
+    fn day06_brute_force() {
+        for seed in 0..20 {
+            let input = generate(6, seed, 3).unwrap();
+            let mut lines = input.lines().map(|line| {
+                line.split_whitespace()
+                    .skip(1)
+                    .map(|number| number.parse::<u64>().unwrap())
+                    .collect::<Vec<_>>()
+            });
+            let (times, distances) = (lines.next().unwrap(), lines.next().unwrap());
+            let expected: i32 = times
+                .iter()
+                .zip(&distances)
+                .map(|(time, distance)| ways_to_win(*time, *distance))
+                .product();
+
+            assert_eq!(Ok(expected), day06::day06a(&input), "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn day10_loop_length() {
+        for seed in 0..20 {
+            let rng = &mut ChaCha8Rng::seed_from_u64(seed);
+            let (lines, loop_length) = pipe_maze(rng, 15);
+            let input = lines.join("\n");
+            assert_eq!(Ok(loop_length / 2), day10::day10a(&input), "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn size_zero() {
+        assert!(matches!(generate(1, 0, 0), Err(AocError::Input(_))));
+    }
+}
@@ -0,0 +1,346 @@
+//! A dense 2d grid for the puzzles that are a picture.
+//!
+//! Positions are `(x, y)` and 0 based. `x` goes left to right and `y` goes top to bottom, the
+//! same as reading the puzzle text: `(0, 0)` is the first character of the first line and
+//! `(3, 1)` the fourth character of the second line. So [`Direction::Up`] lowers `y`.
+
+use crate::error::AocError;
+use serde::{Serialize, Serializer};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+pub type Position = (usize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// `(dx, dy)` of one step in this direction
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+/// the offsets of the 8 surrounding cells, row by row
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// `cells` are row by row, `None` if the amount of cells does not fit `width` and `height`
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Option<Grid<T>> {
+        if cells.len() == width * height {
+            Some(Grid {
+                cells,
+                width,
+                height,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// the cells row by row
+    pub fn into_cells(self) -> Vec<T> {
+        self.cells
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// the position `offset` away from `position`, `None` if that is outside the grid
+    pub fn offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let new_x = x.checked_add_signed(dx)?;
+        let new_y = y.checked_add_signed(dy)?;
+        self.contains((new_x, new_y)).then_some((new_x, new_y))
+    }
+
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        self.offset(position, direction.offset())
+    }
+
+    /// the positions up, down, left and right of `position` that are inside the grid
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// the positions around `position`, diagonals included, that are inside the grid
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        SURROUNDING
+            .into_iter()
+            .filter_map(move |offset| self.offset(position, offset))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[(y * self.width)..((y + 1) * self.width)])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` because `chunks` does not accept 0, an empty grid has no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    /// all positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// all cells with their position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn try_map<U, E>(
+        &self,
+        mut f: impl FnMut(Position, &T) -> Result<U, E>,
+    ) -> Result<Grid<U>, E> {
+        Ok(Grid {
+            cells: self
+                .iter()
+                .map(|(position, cell)| f(position, cell))
+                .collect::<Result<_, _>>()?,
+            width: self.width,
+            height: self.height,
+        })
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then_some(position.1 * self.width + position.0)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// flips the grid over its diagonal, rows become columns
+    pub fn transpose(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .flat_map(|x| (0..self.height).map(move |y| (x, y)))
+            .map(|position| self[position].clone())
+            .collect();
+
+        Grid {
+            cells,
+            width: self.height,
+            height: self.width,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "position {:?} is outside the {}x{} grid",
+                position, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!(
+                "position {:?} is outside the {}x{} grid",
+                position, width, height
+            )
+        })
+    }
+}
+
+/// a list of rows, like the puzzle text
+impl<T: Serialize> Serialize for Grid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.rows())
+    }
+}
+
+/// every line is a row, all lines should be equally long
+impl FromStr for Grid<char> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (line, line_number) in s.lines().zip(1..) {
+            let before = cells.len();
+            cells.extend(line.chars());
+            let line_width = cells.len() - before;
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(AocError::Input(format!(
+                        "line {} is {} characters long, the lines before are {}",
+                        line_number, line_width, width
+                    )))
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn from_str() {
+        let grid = grid();
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!('a', grid[(0, 0)]);
+        assert_eq!('f', grid[(2, 1)]);
+        assert_eq!(None, grid.get((3, 0)));
+        assert!("abc\nde".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = grid();
+        assert_eq!(
+            vec![(1, 1), (0, 0), (2, 0)],
+            grid.neighbours4((1, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 0), (0, 1), (1, 1)],
+            grid.neighbours8((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(Some((1, 0)), grid.step((1, 1), Direction::Up));
+        assert_eq!(None, grid.step((1, 1), Direction::Down));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        assert_eq!(Some(&['d', 'e', 'f'][..]), grid.row(1));
+        assert_eq!(None, grid.row(2));
+        assert_eq!(
+            vec![&'b', &'e'],
+            grid.column(1).unwrap().collect::<Vec<_>>()
+        );
+        assert_eq!(2, grid.rows().count());
+    }
+
+    #[test]
+    fn serialize_as_rows() {
+        assert_eq!(
+            r#"[["a","b","c"],["d","e","f"]]"#,
+            serde_json::to_string(&grid()).unwrap()
+        );
+    }
+
+    #[test]
+    fn transpose() {
+        let transposed = grid().transpose();
+        assert_eq!("ad\nbe\ncf\n", transposed.to_string());
+        assert_eq!(grid(), transposed.transpose());
+    }
+}
@@ -0,0 +1,155 @@
+//! Where the puzzle input comes from, the solvers themselves only ever see the text.
+//!
+//! Every public function of a day that takes the puzzle text, the solvers, parsers, explanations,
+//! pictures and arrays, starts by passing it through [`normalize`]. A BOM, `\r\n` line endings,
+//! tabs or whitespace at the end do not matter to any of them, also when one is called directly.
+
+use crate::error::AocError;
+use std::borrow::Cow;
+use std::io::Read;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Input {
+    Text(String),
+    Bytes(Vec<u8>),
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Input {
+    /// the puzzle input of `day` in the `data` folder, like `data/day05.txt`
+    pub fn for_day(day: u8) -> Input {
+        Input::Path(PathBuf::from(format!("data/day{:02}.txt", day)))
+    }
+
+    /// reads the input to a string, the puzzles are all plain text so the bytes should be utf-8
+    pub fn read(self) -> Result<String, AocError> {
+        match self {
+            Input::Text(text) => Ok(text),
+            Input::Bytes(bytes) => String::from_utf8(bytes)
+                .map_err(|error| AocError::Input(format!("input is not utf-8: {}", error))),
+            Input::Path(path) => std::fs::read_to_string(&path)
+                .map_err(|error| AocError::Input(format!("{}: {}", path.display(), error))),
+            Input::Stdin => {
+                let mut text = String::new();
+                std::io::stdin().read_to_string(&mut text)?;
+                Ok(text)
+            }
+        }
+    }
+}
+
+impl From<String> for Input {
+    fn from(text: String) -> Self {
+        Input::Text(text)
+    }
+}
+
+impl From<&str> for Input {
+    fn from(text: &str) -> Self {
+        Input::Text(text.to_string())
+    }
+}
+
+impl From<Vec<u8>> for Input {
+    fn from(bytes: Vec<u8>) -> Self {
+        Input::Bytes(bytes)
+    }
+}
+
+impl From<PathBuf> for Input {
+    fn from(path: PathBuf) -> Self {
+        Input::Path(path)
+    }
+}
+
+/// the text without a BOM, with `\n` line endings, spaces instead of tabs, no whitespace at the
+/// end of a line and no blank lines at the end. the line numbers stay the same
+pub fn normalize(text: &str) -> Cow<'_, str> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let content = text.trim_end();
+
+    let is_normal = !content.contains(['\r', '\t'])
+        && content
+            .lines()
+            .all(|line| line.len() == line.trim_end().len())
+        && matches!(&text[content.len()..], "" | "\n");
+    if is_normal {
+        return Cow::Borrowed(text);
+    }
+
+    let mut normalized = String::with_capacity(text.len());
+    for line in content.lines() {
+        normalized.push_str(&line.trim_end().replace('\t', " "));
+        normalized.push('\n');
+    }
+    Cow::Owned(normalized)
+}
+
+/// parses a command line argument, `-` is stdin and anything else a path
+impl FromStr for Input {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(Input::Stdin),
+            path => Ok(Input::Path(PathBuf::from(path))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_line_endings() {
+        assert_eq!("a b\n\nc\n", normalize("\u{feff}a\tb \r\n\r\nc\r\n\r\n \n"));
+        assert_eq!("a\nb\n", normalize("a\r\nb"));
+        assert_eq!("", normalize("\u{feff}\r\n"));
+    }
+
+    #[test]
+    fn normalize_keeps_normal_text() {
+        assert!(matches!(
+            normalize("a b\n\nc\n"),
+            Cow::Borrowed("a b\n\nc\n")
+        ));
+        assert!(matches!(normalize("a b\nc"), Cow::Borrowed("a b\nc")));
+        assert!(matches!(normalize("\u{feff}a\n"), Cow::Borrowed("a\n")));
+    }
+
+    #[test]
+    fn normalize_keeps_leading_blank_lines() {
+        assert_eq!("\n\na\n", normalize("\r\n \r\na\r\n"));
+    }
+
+    #[test]
+    fn days_called_directly() {
+        type Solve = fn(&str) -> Result<usize, AocError>;
+        let solvers: [(u8, Solve); 6] = [
+            (1, crate::day01::day01b),
+            (2, crate::day02::day02a),
+            (3, crate::day03::day03b),
+            (4, crate::day04::day04a),
+            (7, crate::day07::day07a),
+            (8, crate::day08::day08b),
+        ];
+        for (day, solve) in solvers {
+            let sample = std::fs::read_to_string(format!("data/day{:02}_debug.txt", day)).unwrap();
+            let windows = format!("\u{feff}{}\r\n", sample.replace('\n', "\r\n"));
+            let answer = solve(&sample).unwrap();
+            assert_eq!(answer, solve(&windows).unwrap(), "day {}", day);
+        }
+
+        let sample = std::fs::read_to_string("data/day10_debug.txt").unwrap();
+        let windows = format!("\u{feff}{}", sample.replace('\n', "\r\n"));
+        assert_eq!(
+            crate::day10::day10a(&sample).unwrap(),
+            crate::day10::day10a(&windows).unwrap()
+        );
+        assert_eq!(Ok(18), crate::day09::day09a("\u{feff}0 3 6 9 12 15\r\n"));
+    }
+}
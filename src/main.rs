@@ -0,0 +1,515 @@
+#[cfg(feature = "count-allocations")]
+use aoc2023::benchmark::CountingAllocator;
+use aoc2023::benchmark::{self, Report, DEFAULT_RUNS};
+use aoc2023::error::{catch_panic, AocError};
+use aoc2023::explain;
+use aoc2023::input::Input;
+use aoc2023::parity::{self, Implementation, Parity};
+use aoc2023::scaffold::NewDay;
+use aoc2023::solver::{self, Part, Solver};
+use aoc2023::verify::{self, Expected, Status};
+use aoc2023::{cache, ffi, generate, model, render, stub};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::fmt;
+use tracing_subscriber::layer::SubscriberExt;
+
+/// the `allocations` of `bench`, only with the opt-in `count-allocations` feature
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs the advent of code 2023 solvers
+#[derive(Debug, Parser)]
+#[command(version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print the diagnostics of the solvers to stderr, like `debug` or `aoc2023::day05=trace`
+    #[arg(long, global = true, env = "AOC_LOG", value_name = "FILTER", value_parser = Targets::from_str)]
+    log: Option<Targets>,
+    /// Cache the answers of `run` in this directory, see `cache`
+    #[arg(long, global = true, env = cache::ENV, value_name = "DIR")]
+    cache: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// List the solvers and whether they are solved
+    List,
+    /// Solve a day, or all of them, and print the answers with their timings
+    Run(Selection),
+    /// Solve a day, or all of them, a number of times and report the timings and allocations
+    Bench(BenchArgs),
+    /// Check the solvers against the known answers
+    Verify(VerifyArgs),
+    /// Solve with the rust, python and dart implementations and compare their answers
+    Parity(ParityArgs),
+    /// Show what every line adds to the answer, for the days that sum over the lines
+    Explain(ExplainArgs),
+    /// Print the parsed puzzle as JSON, or draw it with `--render`
+    Parse(ParseArgs),
+    /// Print a random puzzle input, the same seed gives the same input
+    Generate(GenerateArgs),
+    /// Add the module, data, tests and fuzz target of a new day, existing files are kept
+    NewDay(NewDayArgs),
+    /// List the cached answers, or remove them
+    Cache(CacheArgs),
+    /// Print the python type stub, see `just stub`
+    Stub,
+    /// Print the C header of the shared library, see `just header`
+    Header,
+}
+
+#[derive(Debug, Args)]
+struct Selection {
+    /// The day to solve
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+    /// Only solve this part, solves both parts if not given
+    #[arg(long)]
+    part: Option<Part>,
+    /// Solve every registered day with its `data/dayNN.txt` input
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+    /// The puzzle input, `-` reads from stdin. Defaults to `data/dayNN.txt`
+    #[arg(value_parser = Input::from_str)]
+    input: Option<Input>,
+}
+
+#[derive(Debug, Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+    /// How often every solver runs
+    #[arg(long, default_value_t = DEFAULT_RUNS)]
+    runs: usize,
+    #[arg(long, value_enum, default_value_t = Format::Markdown)]
+    format: Format,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    /// The answers manifest
+    #[arg(long, default_value = verify::MANIFEST)]
+    manifest: PathBuf,
+    /// Only verify this day
+    #[arg(long)]
+    day: Option<u8>,
+}
+
+#[derive(Debug, Args)]
+struct ParityArgs {
+    #[command(flatten)]
+    selection: Selection,
+    /// Only compare these implementations, `rust`, `python` or `dart`. Defaults to all of them
+    #[arg(long = "implementation", value_parser = Implementation::from_str)]
+    implementations: Vec<Implementation>,
+}
+
+#[derive(Debug, Args)]
+struct ParseArgs {
+    /// The day of the puzzle
+    #[arg(long)]
+    day: u8,
+    /// Draw the puzzle with what the solver decided instead, as `ansi` colored text or `svg`
+    #[arg(long, value_name = "FORMAT", value_parser = render::Format::from_str)]
+    render: Option<render::Format>,
+    /// The puzzle input, `-` reads from stdin. Defaults to `data/dayNN.txt`
+    #[arg(value_parser = Input::from_str)]
+    input: Option<Input>,
+}
+
+#[derive(Debug, Args)]
+struct ExplainArgs {
+    #[arg(long)]
+    day: u8,
+    #[arg(long)]
+    part: Part,
+    #[arg(long, value_enum, default_value_t = Format::Markdown)]
+    format: Format,
+    /// The puzzle input, `-` reads from stdin. Defaults to `data/dayNN.txt`
+    #[arg(value_parser = Input::from_str)]
+    input: Option<Input>,
+}
+
+#[derive(Debug, Args)]
+struct GenerateArgs {
+    /// The day of the puzzle
+    #[arg(long)]
+    day: u8,
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// The amount of lines, or about the width for the days that are a grid
+    #[arg(long, default_value_t = 10)]
+    size: usize,
+}
+
+#[derive(Debug, Args)]
+struct NewDayArgs {
+    #[arg(long)]
+    day: u8,
+    /// The example of the puzzle text for `data/dayNN_debug.txt`, `-` reads from stdin
+    #[arg(long, value_parser = Input::from_str)]
+    sample: Option<Input>,
+    /// The answer of part a for the sample, the generated tests check it
+    #[arg(long)]
+    answer_a: Option<usize>,
+    /// The answer of part b for the sample
+    #[arg(long)]
+    answer_b: Option<usize>,
+}
+
+#[derive(Debug, Args)]
+struct CacheArgs {
+    /// The cache directory
+    #[arg(long, env = cache::ENV)]
+    dir: PathBuf,
+    /// Remove the cached answers instead of listing them
+    #[arg(long)]
+    clear: bool,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Json,
+    Markdown,
+}
+
+/// prints the error, unsolved parts are not counted as failures
+fn report_error(label: &str, error: AocError) -> bool {
+    match error {
+        AocError::NotSolved { .. } => {
+            eprintln!("{}: unsolved", label);
+            true
+        }
+        error => {
+            eprintln!("{}: {}", label, error);
+            false
+        }
+    }
+}
+
+fn run_solver(solver: &'static dyn Solver, input: &str) -> bool {
+    let label = format!("day {:02} {}", solver.day(), solver.part());
+    let run = solver::run(solver, input);
+    match run.result {
+        Ok(answer) if run.cached => {
+            println!("{}: {} (cached)", label, answer);
+            true
+        }
+        Ok(answer) => {
+            println!("{}: {} ({:?})", label, answer, run.duration);
+            true
+        }
+        Err(error) => report_error(&label, error),
+    }
+}
+
+fn bench_solver(
+    solver: &'static dyn Solver,
+    input: &str,
+    runs: usize,
+    reports: &mut Vec<Report>,
+) -> bool {
+    let label = format!("day {:02} {}", solver.day(), solver.part());
+    match benchmark::benchmark(solver, input, runs) {
+        Ok(report) => {
+            reports.push(report);
+            true
+        }
+        Err(error) => report_error(&label, error),
+    }
+}
+
+fn new_day(args: NewDayArgs) -> bool {
+    let sample = match args.sample.map(|input| input.read()).transpose() {
+        Ok(sample) => sample,
+        Err(error) => {
+            eprintln!("sample: {}", error);
+            return false;
+        }
+    };
+    let new_day = NewDay {
+        day: args.day,
+        sample,
+        answer_a: args.answer_a,
+        answer_b: args.answer_b,
+    };
+
+    match new_day.create(Path::new(".")) {
+        Ok(changes) => {
+            for (path, change) in changes {
+                println!("{}: {}", path.display(), change);
+            }
+            println!(
+                "run `just stub header` to add day {} to {} and {}",
+                args.day,
+                stub::PATH,
+                ffi::HEADER_PATH
+            );
+            true
+        }
+        Err(error) => {
+            eprintln!("day {:02}: {}", args.day, error);
+            false
+        }
+    }
+}
+
+fn cache(args: CacheArgs) -> bool {
+    let cache = cache::Cache::new(args.dir);
+    let result = if args.clear {
+        cache
+            .clear()
+            .map(|count| println!("removed {} cached answers", count))
+    } else {
+        cache.entries().map(|entries| {
+            for entry in entries {
+                println!(
+                    "day {:02} {}: {} (input {}, version {})",
+                    entry.day, entry.part, entry.answer, entry.input, entry.version
+                );
+            }
+        })
+    };
+
+    match result {
+        Ok(()) => true,
+        Err(error) => {
+            eprintln!("{}: {}", cache.directory().display(), error);
+            false
+        }
+    }
+}
+
+fn list() -> bool {
+    for solver in solver::solvers() {
+        let status = if solver.solved() {
+            "solved"
+        } else {
+            "unsolved"
+        };
+        println!(
+            "day {:02} {}: {} ({})",
+            solver.day(),
+            solver.part(),
+            solver.name(),
+            status
+        );
+    }
+
+    true
+}
+
+/// calls `f` for every selected solver with its input, returns whether every call succeeded
+fn for_each_solver(
+    selection: &Selection,
+    mut f: impl FnMut(&'static dyn Solver, &str) -> bool,
+) -> bool {
+    let selected: Vec<&'static dyn Solver> = solver::solvers()
+        .iter()
+        .copied()
+        .filter(|solver| selection.all || Some(solver.day()) == selection.day)
+        .filter(|solver| selection.part.is_none_or(|part| part == solver.part()))
+        .collect();
+
+    if selected.is_empty() {
+        eprintln!("no solvers found");
+        return false;
+    }
+
+    // both parts share the same input, and stdin can only be read once
+    let mut inputs: HashMap<u8, String> = HashMap::new();
+    let mut all_ok = true;
+
+    for solver in selected {
+        let input = match inputs.entry(solver.day()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let input = selection
+                    .input
+                    .clone()
+                    .unwrap_or_else(|| Input::for_day(solver.day()));
+                match input.read() {
+                    Ok(input) => entry.insert(input),
+                    Err(error) => {
+                        eprintln!("day {:02} {}: {}", solver.day(), solver.part(), error);
+                        all_ok = false;
+                        continue;
+                    }
+                }
+            }
+        };
+
+        all_ok &= f(solver, input);
+    }
+
+    all_ok
+}
+
+fn run(selection: Selection) -> bool {
+    for_each_solver(&selection, run_solver)
+}
+
+fn bench(args: BenchArgs) -> bool {
+    let mut reports = Vec::new();
+    let all_ok = for_each_solver(&args.selection, |solver, input| {
+        bench_solver(solver, input, args.runs, &mut reports)
+    });
+
+    match args.format {
+        Format::Json => println!("{}", benchmark::to_json(&reports)),
+        Format::Markdown => print!("{}", benchmark::to_markdown(&reports)),
+    }
+
+    all_ok
+}
+
+fn parse(args: ParseArgs) -> bool {
+    let input = args.input.unwrap_or_else(|| Input::for_day(args.day));
+    let output = input.read().and_then(|text| match args.render {
+        Some(format) => catch_panic(args.day, || {
+            render::render(args.day, &text).map(|picture| picture.render(format))
+        }),
+        None => model::to_json(args.day, &text).map(|json| json + "\n"),
+    });
+
+    match output {
+        Ok(output) => {
+            print!("{}", output);
+            true
+        }
+        Err(error) => report_error(&format!("day {:02}", args.day), error),
+    }
+}
+
+fn explain(args: ExplainArgs) -> bool {
+    let input = args.input.unwrap_or_else(|| Input::for_day(args.day));
+    let explanations = input
+        .read()
+        .and_then(|text| catch_panic(args.day, || explain::explain(args.day, args.part, &text)));
+
+    match explanations {
+        Ok(explanations) => {
+            match args.format {
+                Format::Json => println!("{}", explain::to_json(&explanations)),
+                Format::Markdown => print!("{}", explain::to_markdown(&explanations)),
+            }
+            true
+        }
+        Err(error) => report_error(&format!("day {:02} {}", args.day, args.part), error),
+    }
+}
+
+fn generate(args: GenerateArgs) -> bool {
+    match generate::generate(args.day, args.seed, args.size) {
+        Ok(input) => {
+            print!("{}", input);
+            true
+        }
+        Err(error) => report_error(&format!("day {:02}", args.day), error),
+    }
+}
+
+fn verify(args: VerifyArgs) -> bool {
+    let entries = match verify::load(&args.manifest) {
+        Ok(entries) => entries,
+        Err(error) => {
+            eprintln!("{}", error);
+            return false;
+        }
+    };
+    let entries: Vec<Expected> = entries
+        .into_iter()
+        .filter(|entry| args.day.is_none_or(|day| day == entry.day))
+        .collect();
+
+    let (mut passed, mut failed, mut unsolved) = (0, 0, 0);
+    for verification in verify::verify(&entries) {
+        let entry = &verification.entry;
+        println!(
+            "day {:02} {} {}: {}",
+            entry.day,
+            entry.part,
+            entry.input.display(),
+            verification.status
+        );
+        match verification.status {
+            Status::Pass => passed += 1,
+            Status::Fail(_) => failed += 1,
+            Status::Unsolved => unsolved += 1,
+        }
+    }
+    println!(
+        "{} passed, {} failed, {} unsolved",
+        passed, failed, unsolved
+    );
+
+    failed == 0
+}
+
+fn parity(args: ParityArgs) -> bool {
+    let implementations = if args.implementations.is_empty() {
+        Implementation::all()
+    } else {
+        args.implementations
+    };
+
+    for_each_solver(&args.selection, |solver, input| {
+        let comparison = parity::compare(&implementations, solver.day(), solver.part(), input);
+        println!("{}", comparison);
+        comparison.parity() != Parity::Mismatch
+    })
+}
+
+/// nothing is logged without a filter
+fn init_logging(filter: Option<Targets>) {
+    if let Some(filter) = filter {
+        let subscriber = tracing_subscriber::registry()
+            .with(fmt::layer().with_writer(std::io::stderr))
+            .with(filter);
+        tracing::subscriber::set_global_default(subscriber)
+            .expect("logging is only initialized once");
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging(cli.log);
+    if let Some(directory) = cli.cache {
+        cache::enable(directory);
+    }
+
+    let all_ok = match cli.command {
+        Command::List => list(),
+        Command::Run(selection) => run(selection),
+        Command::Bench(args) => bench(args),
+        Command::Verify(args) => verify(args),
+        Command::Parity(args) => parity(args),
+        Command::Explain(args) => explain(args),
+        Command::Parse(args) => parse(args),
+        Command::Generate(args) => generate(args),
+        Command::NewDay(args) => new_day(args),
+        Command::Cache(args) => cache(args),
+        Command::Stub => {
+            print!("{}", stub::python_stub());
+            true
+        }
+        Command::Header => {
+            print!("{}", ffi::c_header());
+            true
+        }
+    };
+
+    if all_ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,66 @@
+//! The parsed puzzle of every day as JSON, to look at the inputs without solving them.
+
+use crate::error::AocError;
+use crate::registry;
+use serde::Serialize;
+use serde_json::Value;
+
+/// the days that have a parser
+pub const DAYS: &[u8] = registry::DAYS;
+
+pub(crate) fn to_value<T: Serialize>(
+    day: u8,
+    model: Result<T, AocError>,
+) -> Result<Value, AocError> {
+    serde_json::to_value(model?)
+        .map_err(|error| AocError::Input(format!("day {:02}: {}", day, error)))
+}
+
+/// parses `input` as the puzzle of `day`
+pub fn parse(day: u8, input: &str) -> Result<Value, AocError> {
+    registry::parse(day, input)
+}
+
+pub fn to_json(day: u8, input: &str) -> Result<String, AocError> {
+    let value = parse(day, input)?;
+    Ok(serde_json::to_string_pretty(&value).expect("a json value is valid json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn every_day_parses_its_sample() {
+        for &day in DAYS {
+            let input = std::fs::read_to_string(format!("data/day{:02}_debug.txt", day)).unwrap();
+            let value = parse(day, &input).unwrap();
+            assert!(value.is_object(), "day {} is not an object", day);
+        }
+    }
+
+    #[test]
+    fn day02_games() {
+        assert_eq!(
+            json!({"games": [{"id": 1, "hands": [
+                {"red": 4, "green": 0, "blue": 3},
+                {"red": 1, "green": 2, "blue": 0},
+            ]}]}),
+            parse(2, "Game 1: 3 blue, 4 red; 1 red, 2 green").unwrap()
+        );
+    }
+
+    #[test]
+    fn day08_nodes() {
+        assert_eq!(
+            json!({"instructions": "LR", "nodes": {"AAA": ["BBB", "CCC"]}}),
+            parse(8, "LR\n\nAAA = (BBB, CCC)").unwrap()
+        );
+    }
+
+    #[test]
+    fn unknown_day() {
+        assert!(matches!(parse(26, ""), Err(AocError::Input(_))));
+    }
+}
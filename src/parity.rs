@@ -0,0 +1,370 @@
+//! Runs every implementation of a day on the same input and compares the answers.
+//!
+//! Next to the rust solvers there are the python reference modules in `reference/` and the dart
+//! solutions of `bin/parity.dart`. Those run as a command, `program args.. DAY PART` with the
+//! puzzle on stdin and the answer on stdout. A command that exits with [`NOT_IMPLEMENTED`] has no
+//! solution for that part.
+
+use crate::error::{catch_panic, AocError};
+use crate::solver::{self, Answer, Part};
+use serde::Serialize;
+use std::fmt;
+use std::io::{ErrorKind, Write};
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+use tracing::debug;
+
+/// the exit code of a command without a solution for the part
+pub const NOT_IMPLEMENTED: i32 = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Implementation {
+    /// the solvers of this crate
+    Rust,
+    Command {
+        name: String,
+        program: String,
+        args: Vec<String>,
+    },
+}
+
+impl Implementation {
+    /// `python3 -m reference`, the modules in `reference/`
+    pub fn python() -> Implementation {
+        Implementation::command("python", "python3", &["-m", "reference"])
+    }
+
+    /// `dart run bin/parity.dart`
+    pub fn dart() -> Implementation {
+        Implementation::command("dart", "dart", &["run", "bin/parity.dart"])
+    }
+
+    pub fn command(name: &str, program: &str, args: &[&str]) -> Implementation {
+        Implementation::Command {
+            name: name.to_string(),
+            program: program.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+
+    /// rust, python and dart
+    pub fn all() -> Vec<Implementation> {
+        vec![
+            Implementation::Rust,
+            Implementation::python(),
+            Implementation::dart(),
+        ]
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Implementation::Rust => "rust",
+            Implementation::Command { name, .. } => name,
+        }
+    }
+
+    pub fn solve(&self, day: u8, part: Part, input: &str) -> Outcome {
+        match self {
+            Implementation::Rust => {
+                let Some(solver) = solver::find(day, part) else {
+                    return Outcome::Missing;
+                };
+                match catch_panic(day, || solver.solve(input)) {
+                    Ok(answer) => Outcome::Answer(answer),
+                    Err(AocError::NotSolved { .. }) => Outcome::Missing,
+                    Err(error) => Outcome::Error(error.to_string()),
+                }
+            }
+            Implementation::Command {
+                name,
+                program,
+                args,
+            } => run_command(name, program, args, day, part, input),
+        }
+    }
+}
+
+impl fmt::Display for Implementation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Implementation {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rust" => Ok(Implementation::Rust),
+            "python" => Ok(Implementation::python()),
+            "dart" => Ok(Implementation::dart()),
+            _ => Err(AocError::Input(format!(
+                "implementation should be 'rust', 'python' or 'dart', not {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+fn run_command(
+    name: &str,
+    program: &str,
+    args: &[String],
+    day: u8,
+    part: Part,
+    input: &str,
+) -> Outcome {
+    let child = Command::new(program)
+        .args(args)
+        .arg(day.to_string())
+        .arg(part.to_string())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        // the language is not installed, that is not a disagreement
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            debug!(name, program, "not installed");
+            return Outcome::Missing;
+        }
+        Err(error) => return Outcome::Error(format!("{}: {}", program, error)),
+    };
+
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    // written from its own thread, a command that prints a lot before it reads the input would
+    // otherwise block on a full stdout while this blocks on a full stdin
+    let output = std::thread::scope(|scope| {
+        scope.spawn(move || {
+            // a command without a solution may exit before it reads the input
+            if let Err(error) = stdin.write_all(input.as_bytes()) {
+                debug!(name, %error, "could not write the input");
+            }
+        });
+        child.wait_with_output()
+    });
+    let output = match output {
+        Ok(output) => output,
+        Err(error) => return Outcome::Error(format!("{}: {}", program, error)),
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    match output.status.code() {
+        Some(0) => parse_answer(stdout.trim()),
+        Some(NOT_IMPLEMENTED) => Outcome::Missing,
+        _ => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let message = stderr.lines().last().unwrap_or_default();
+            Outcome::Error(format!("{} ({})", message, output.status))
+        }
+    }
+}
+
+fn parse_answer(text: &str) -> Outcome {
+    if let Ok(number) = text.parse::<usize>() {
+        Outcome::Answer(Answer::Unsigned(number))
+    } else if let Ok(number) = text.parse::<i64>() {
+        Outcome::Answer(Answer::Signed(number))
+    } else {
+        Outcome::Error(format!("expected a number but printed {:?}", text))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "outcome", content = "value", rename_all = "lowercase")]
+pub enum Outcome {
+    Answer(Answer),
+    /// there is no solution of the part in that language
+    Missing,
+    /// an error, a panic or a command that failed
+    Error(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{}", answer),
+            Outcome::Missing => write!(f, "missing"),
+            Outcome::Error(message) => write!(f, "error: {}", message),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Parity {
+    /// at least two answers and they are the same
+    Agree,
+    /// different answers, or an answer next to an error
+    Mismatch,
+    /// less than two answers to compare
+    Unchecked,
+}
+
+impl fmt::Display for Parity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Parity::Agree => write!(f, "agree"),
+            Parity::Mismatch => write!(f, "mismatch"),
+            Parity::Unchecked => write!(f, "unchecked"),
+        }
+    }
+}
+
+/// the outcome of every implementation of one part, in the order they were given
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: Part,
+    pub outcomes: Vec<(String, Outcome)>,
+}
+
+impl Comparison {
+    pub fn parity(&self) -> Parity {
+        let mut answers = Vec::new();
+        let mut errors = 0;
+        for (_, outcome) in &self.outcomes {
+            match outcome {
+                Outcome::Answer(answer) => answers.push(*answer),
+                Outcome::Missing => (),
+                Outcome::Error(_) => errors += 1,
+            }
+        }
+
+        match answers.first() {
+            Some(first) if answers.iter().any(|answer| answer != first) || errors > 0 => {
+                Parity::Mismatch
+            }
+            _ if answers.len() >= 2 => Parity::Agree,
+            _ => Parity::Unchecked,
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {:02} {}: {} (", self.day, self.part, self.parity())?;
+        for (index, (name, outcome)) in self.outcomes.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} {}", name, outcome)?;
+        }
+        write!(f, ")")
+    }
+}
+
+pub fn compare(implementations: &[Implementation], day: u8, part: Part, input: &str) -> Comparison {
+    let outcomes = implementations
+        .iter()
+        .map(|implementation| {
+            let outcome = implementation.solve(day, part, input);
+            debug!(day, %part, implementation = implementation.name(), %outcome);
+            (implementation.name().to_string(), outcome)
+        })
+        .collect();
+
+    Comparison {
+        day,
+        part,
+        outcomes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comparison(outcomes: Vec<Outcome>) -> Comparison {
+        Comparison {
+            day: 1,
+            part: Part::A,
+            outcomes: outcomes
+                .into_iter()
+                .enumerate()
+                .map(|(index, outcome)| (index.to_string(), outcome))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parity() {
+        let five = || Outcome::Answer(Answer::Unsigned(5));
+        let error = || Outcome::Error(String::from("oops"));
+
+        assert_eq!(
+            Parity::Agree,
+            comparison(vec![five(), Outcome::Missing, five()]).parity()
+        );
+        assert_eq!(
+            Parity::Mismatch,
+            comparison(vec![five(), Outcome::Answer(Answer::Signed(-5))]).parity()
+        );
+        assert_eq!(Parity::Mismatch, comparison(vec![five(), error()]).parity());
+        assert_eq!(
+            Parity::Unchecked,
+            comparison(vec![five(), Outcome::Missing]).parity()
+        );
+        assert_eq!(
+            Parity::Unchecked,
+            comparison(vec![error(), error()]).parity()
+        );
+    }
+
+    #[test]
+    fn rust_outcomes() {
+        assert_eq!(
+            Outcome::Answer(Answer::Signed(5)),
+            Implementation::Rust.solve(9, Part::B, "10 13 16 21 30 45")
+        );
+        assert_eq!(
+            Outcome::Missing,
+            Implementation::Rust.solve(11, Part::A, "")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn command_outcomes() {
+        let echo = Implementation::command("sh", "sh", &["-c", "cat > /dev/null; echo 142"]);
+        assert_eq!(
+            Outcome::Answer(Answer::Unsigned(142)),
+            echo.solve(1, Part::A, "1abc2")
+        );
+
+        let missing = Implementation::command("sh", "sh", &["-c", "exit 3"]);
+        assert_eq!(Outcome::Missing, missing.solve(1, Part::A, ""));
+
+        let not_installed = Implementation::command("nope", "aoc2023-not-installed", &[]);
+        assert_eq!(Outcome::Missing, not_installed.solve(1, Part::A, ""));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn command_prints_before_reading() {
+        // more than fits in a pipe, both ways
+        let chatty = Implementation::command(
+            "sh",
+            "sh",
+            &[
+                "-c",
+                "head -c 1000000 /dev/zero >&2; cat > /dev/null; echo 7",
+            ],
+        );
+        let input = "1\n".repeat(500_000);
+        assert_eq!(
+            Outcome::Answer(Answer::Unsigned(7)),
+            chatty.solve(1, Part::A, &input)
+        );
+    }
+
+    #[test]
+    fn display() {
+        let comparison = comparison(vec![Outcome::Answer(Answer::Unsigned(5)), Outcome::Missing]);
+        assert_eq!(
+            "day 01 a: unchecked (0 5, 1 missing)",
+            comparison.to_string()
+        );
+    }
+}
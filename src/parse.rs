@@ -0,0 +1,327 @@
+//! Small parser combinators for the puzzle inputs.
+//!
+//! A [`Parser`] walks over a single line. Every token skips the whitespace in front of it, so
+//! extra spaces, tabs or the `\r` of a `\r\n` line ending do not matter. Errors point at the
+//! column of the text that did not match, [`line`], [`lines`] and [`Block`] add the line number.
+
+use crate::error::AocError;
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct Parser<'a> {
+    day: u8,
+    line: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(day: u8, line: &'a str) -> Parser<'a> {
+        Parser {
+            day,
+            line,
+            rest: line,
+        }
+    }
+
+    /// the text that is not parsed yet
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_done(&mut self) -> bool {
+        self.skip_whitespace();
+        self.rest.is_empty()
+    }
+
+    /// an error for `found`, that should be a slice of the line
+    pub fn error_at(&self, found: &str, expected: &str) -> AocError {
+        AocError::parse(self.day, self.line, found, expected)
+    }
+
+    /// an error for the next whitespace separated piece of the line
+    pub fn error(&self, expected: &str) -> AocError {
+        let rest = self.rest.trim_start();
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        self.error_at(&rest[..end], expected)
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    fn advance(&mut self, length: usize) -> &'a str {
+        let (taken, rest) = self.rest.split_at(length);
+        self.rest = rest;
+        taken
+    }
+
+    /// `tag` literally, like `"Game"` or `"|"`
+    pub fn tag(&mut self, tag: &str) -> Result<(), AocError> {
+        self.skip_whitespace();
+        if self.rest.starts_with(tag) {
+            self.advance(tag.len());
+            Ok(())
+        } else {
+            Err(self.error(&format!("'{}'", tag)))
+        }
+    }
+
+    /// `label` followed by a `:`, like `"seeds:"`
+    pub fn label(&mut self, label: &str) -> Result<(), AocError> {
+        self.tag(label)?;
+        self.tag(":")
+    }
+
+    /// the characters matching `predicate`, can be empty
+    pub fn take_while(&mut self, mut predicate: impl FnMut(char) -> bool) -> &'a str {
+        self.skip_whitespace();
+        let length = self
+            .rest
+            .find(|ch| !predicate(ch))
+            .unwrap_or(self.rest.len());
+        self.advance(length)
+    }
+
+    /// letters and digits, at least one
+    pub fn word(&mut self) -> Result<&'a str, AocError> {
+        let word = self.take_while(char::is_alphanumeric);
+        if word.is_empty() {
+            Err(self.error("a word"))
+        } else {
+            Ok(word)
+        }
+    }
+
+    fn starts_with_number(&mut self) -> bool {
+        self.skip_whitespace();
+        let mut chars = self.rest.chars();
+        match chars.next() {
+            Some('-') => chars.next().is_some_and(|ch| ch.is_ascii_digit()),
+            Some(ch) => ch.is_ascii_digit(),
+            None => false,
+        }
+    }
+
+    /// the digits of a number, with its sign
+    pub fn digits(&mut self) -> Result<&'a str, AocError> {
+        if !self.starts_with_number() {
+            return Err(self.error("a number"));
+        }
+
+        let sign = usize::from(self.rest.starts_with('-'));
+        let length = self.rest[sign..]
+            .find(|ch: char| !ch.is_ascii_digit())
+            .map_or(self.rest.len(), |length| sign + length);
+        Ok(self.advance(length))
+    }
+
+    pub fn number<T: FromStr>(&mut self) -> Result<T, AocError> {
+        let digits = self.digits()?;
+        digits
+            .parse()
+            .map_err(|_| self.error_at(digits, "a number"))
+    }
+
+    /// whitespace separated numbers, stops at the first thing that is not a number
+    pub fn numbers<T: FromStr>(&mut self) -> Result<Vec<T>, AocError> {
+        let mut numbers = Vec::new();
+        while self.starts_with_number() {
+            numbers.push(self.number()?);
+        }
+        Ok(numbers)
+    }
+
+    /// one or more `item`s with `separator` in between
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Parser<'a>) -> Result<T, AocError>,
+    ) -> Result<Vec<T>, AocError> {
+        let mut items = vec![item(self)?];
+        loop {
+            self.skip_whitespace();
+            if !self.rest.starts_with(separator) {
+                return Ok(items);
+            }
+            self.advance(separator.len());
+            items.push(item(self)?);
+        }
+    }
+
+    /// there should be nothing but whitespace left
+    pub fn end(&mut self) -> Result<(), AocError> {
+        if self.is_done() {
+            Ok(())
+        } else {
+            Err(self.error("the end of the line"))
+        }
+    }
+}
+
+/// parses one whole line with `f`, `line_number` is added to the error
+pub fn line<'a, T>(
+    day: u8,
+    text: &'a str,
+    line_number: usize,
+    f: impl FnOnce(&mut Parser<'a>) -> Result<T, AocError>,
+) -> Result<T, AocError> {
+    let mut parser = Parser::new(day, text);
+    f(&mut parser)
+        .and_then(|value| parser.end().map(|_| value))
+        .map_err(|error| error.at_line(line_number))
+}
+
+/// the lines that are not blank, with their line numbers
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (&str, usize)> {
+    input
+        .lines()
+        .zip(1..)
+        .filter(|(text, _)| !text.trim().is_empty())
+}
+
+/// parses every line that is not blank with `f`
+pub fn lines<'a, T>(
+    day: u8,
+    input: &'a str,
+    mut f: impl FnMut(&mut Parser<'a>) -> Result<T, AocError>,
+) -> Result<Vec<T>, AocError> {
+    numbered_lines(input)
+        .map(|(text, line_number)| line(day, text, line_number, &mut f))
+        .collect()
+}
+
+/// lines that belong together, between blank lines
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block<'a> {
+    /// the line number of `lines[0]`
+    pub line_number: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Block<'a> {
+    /// the lines with their line numbers
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (&'a str, usize)> + '_ {
+        self.lines.iter().copied().zip(self.line_number..)
+    }
+
+    /// parses the first line with `first` and the other lines with `rest`
+    pub fn parse<H, T>(
+        &self,
+        day: u8,
+        first: impl FnOnce(&mut Parser<'a>) -> Result<H, AocError>,
+        mut rest: impl FnMut(&mut Parser<'a>) -> Result<T, AocError>,
+    ) -> Result<(H, Vec<T>), AocError> {
+        let mut numbered_lines = self.numbered_lines();
+        let (text, line_number) = numbered_lines
+            .next()
+            .expect("blocks have at least one line");
+        let header = line(day, text, line_number, first)?;
+        let items = numbered_lines
+            .map(|(text, line_number)| line(day, text, line_number, &mut rest))
+            .collect::<Result<_, _>>()?;
+
+        Ok((header, items))
+    }
+}
+
+/// splits the input on blank lines, a block always has at least one line
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut current: Option<Block> = None;
+
+    for (text, line_number) in input.lines().zip(1..) {
+        if text.trim().is_empty() {
+            blocks.extend(current.take());
+        } else {
+            current
+                .get_or_insert_with(|| Block {
+                    line_number,
+                    lines: Vec::new(),
+                })
+                .lines
+                .push(text);
+        }
+    }
+    blocks.extend(current);
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_skip_whitespace() {
+        let mut parser = Parser::new(1, "Card  12:\t41 -3 |\r");
+        assert_eq!(Ok(()), parser.tag("Card"));
+        assert_eq!(Ok(12), parser.number::<usize>());
+        assert_eq!(Ok(()), parser.tag(":"));
+        assert_eq!(Ok(vec![41, -3]), parser.numbers::<i64>());
+        assert_eq!(Ok(()), parser.tag("|"));
+        assert_eq!(Ok(()), parser.end());
+    }
+
+    #[test]
+    fn error_column() {
+        let mut parser = Parser::new(2, "3 blue, 4 rad");
+        parser
+            .separated(",", |parser| {
+                parser.number::<usize>()?;
+                parser.word()
+            })
+            .unwrap();
+        assert_eq!(Ok(()), parser.end());
+
+        let line = "3 blue 4 red";
+        let mut parser = Parser::new(2, line);
+        parser.number::<usize>().unwrap();
+        parser.word().unwrap();
+        assert_eq!(
+            Err(AocError::parse(2, line, &line[7..8], "the end of the line")),
+            parser.end()
+        );
+    }
+
+    #[test]
+    fn number_out_of_range() {
+        let line = "x: 300";
+        let mut parser = Parser::new(3, line);
+        parser.label("x").unwrap();
+        assert!(matches!(
+            parser.number::<u8>(),
+            Err(AocError::Parse { column: 4, .. })
+        ));
+    }
+
+    #[test]
+    fn lines_add_line_number() {
+        let error = lines(4, "1 2\n\n3 x", |parser| parser.numbers::<usize>()).unwrap_err();
+        assert!(matches!(
+            error,
+            AocError::Parse {
+                line: 3,
+                column: 3,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn blocks_split_on_blank_lines() {
+        let blocks = blocks("a\nb\n\n  \nc\n");
+        assert_eq!(
+            vec![
+                Block {
+                    line_number: 1,
+                    lines: vec!["a", "b"]
+                },
+                Block {
+                    line_number: 5,
+                    lines: vec!["c"]
+                }
+            ],
+            blocks
+        );
+    }
+}
@@ -0,0 +1,605 @@
+//! Python bindings, a thin layer over the rest of the crate.
+//! The solvers are exported as `dayNNa(input)` and `dayNNb(input)`, the parsed puzzles as
+//! `dayNN_parse(input)`. See [`Input`] for what `input` can be.
+
+use crate::benchmark::{self, Report, DEFAULT_RUNS};
+use crate::day02::HandTuple;
+use crate::day03::{SchemaToken, Tokenizer};
+use crate::error::{catch_panic, AocError};
+use crate::input::Input;
+use crate::solver::{self, Answer, Part, Run, Solver};
+use crate::verify::{self, Status, Verification};
+use crate::{cache, day02, day03, day07, explain, generate, model, render};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyCFunction, PyDict, PyList, PyString};
+use rayon::prelude::*;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[cfg(feature = "numpy")]
+mod arrays;
+mod logging;
+
+pub mod exceptions {
+    use pyo3::create_exception;
+    use pyo3::exceptions::PyValueError;
+
+    // subclass of ValueError, so code that caught the old errors keeps working
+    create_exception!(
+        aoc2023,
+        AocError,
+        PyValueError,
+        "Base class of all aoc2023 errors."
+    );
+    create_exception!(
+        aoc2023,
+        ParseError,
+        AocError,
+        "The input could not be parsed."
+    );
+    create_exception!(
+        aoc2023,
+        InputError,
+        AocError,
+        "The input could not be read."
+    );
+    create_exception!(
+        aoc2023,
+        UnsolvableError,
+        AocError,
+        "The input parsed, but has no answer."
+    );
+    create_exception!(
+        aoc2023,
+        NotSolvedError,
+        AocError,
+        "The part is not solved yet."
+    );
+    create_exception!(
+        aoc2023,
+        PanicError,
+        AocError,
+        "A bug in a solver, it panicked."
+    );
+}
+
+impl From<AocError> for PyErr {
+    fn from(error: AocError) -> Self {
+        let message = error.to_string();
+
+        Python::with_gil(|py| {
+            let (err, attributes): (PyErr, Vec<(&str, PyObject)>) = match error {
+                AocError::Parse {
+                    day,
+                    line,
+                    column,
+                    text,
+                    expected,
+                } => (
+                    exceptions::ParseError::new_err(message),
+                    vec![
+                        ("day", day.into_py(py)),
+                        ("line", line.into_py(py)),
+                        ("column", column.into_py(py)),
+                        ("text", text.into_py(py)),
+                        ("expected", expected.into_py(py)),
+                    ],
+                ),
+                AocError::Input(_) => (exceptions::InputError::new_err(message), Vec::new()),
+                AocError::Unsolvable { day, .. } => (
+                    exceptions::UnsolvableError::new_err(message),
+                    vec![("day", day.into_py(py))],
+                ),
+                AocError::NotSolved { day, part } => (
+                    exceptions::NotSolvedError::new_err(message),
+                    vec![
+                        ("day", day.into_py(py)),
+                        ("part", part.to_string().into_py(py)),
+                    ],
+                ),
+                AocError::Panicked { day, .. } => (
+                    exceptions::PanicError::new_err(message),
+                    vec![("day", day.into_py(py))],
+                ),
+            };
+
+            let value = err.value(py);
+            for (name, attribute) in attributes {
+                if let Err(setattr_error) = value.setattr(name, attribute) {
+                    return setattr_error;
+                }
+            }
+
+            err
+        })
+    }
+}
+
+impl IntoPy<Py<PyAny>> for Answer {
+    fn into_py(self, py: Python<'_>) -> Py<PyAny> {
+        match self {
+            Answer::Unsigned(number) => number.into_py(py),
+            Answer::Signed(number) => number.into_py(py),
+        }
+    }
+}
+
+impl IntoPy<Py<PyAny>> for Report {
+    fn into_py(self, py: Python<'_>) -> Py<PyAny> {
+        let dict = PyDict::new(py);
+        let items: [(&str, PyObject); 9] = [
+            ("day", self.day.into_py(py)),
+            ("part", self.part.to_string().into_py(py)),
+            ("name", self.name.into_py(py)),
+            ("answer", self.answer.into_py(py)),
+            ("runs", self.runs.into_py(py)),
+            ("min", self.min.as_secs_f64().into_py(py)),
+            ("median", self.median.as_secs_f64().into_py(py)),
+            ("max", self.max.as_secs_f64().into_py(py)),
+            ("allocations", self.allocations.into_py(py)),
+        ];
+        for (key, value) in items {
+            dict.set_item(key, value)
+                .expect("setting a str key on a new dict does not fail");
+        }
+        dict.into_py(py)
+    }
+}
+
+impl IntoPy<Py<PyAny>> for Verification {
+    fn into_py(self, py: Python<'_>) -> Py<PyAny> {
+        let (status, message) = match self.status {
+            Status::Pass => ("pass", None),
+            Status::Fail(message) => ("fail", Some(message)),
+            Status::Unsolved => ("unsolved", None),
+        };
+        let dict = PyDict::new(py);
+        let items: [(&str, PyObject); 6] = [
+            ("day", self.entry.day.into_py(py)),
+            ("part", self.entry.part.to_string().into_py(py)),
+            ("input", self.entry.input.into_py(py)),
+            ("expected", self.entry.expected.into_py(py)),
+            ("status", status.into_py(py)),
+            ("message", message.into_py(py)),
+        ];
+        for (key, value) in items {
+            dict.set_item(key, value)
+                .expect("setting a str key on a new dict does not fail");
+        }
+        dict.into_py(py)
+    }
+}
+
+impl IntoPy<Py<PyAny>> for Run {
+    fn into_py(self, py: Python<'_>) -> Py<PyAny> {
+        let (status, answer, error) = match self.result {
+            Ok(answer) => ("solved", Some(answer), None),
+            Err(AocError::NotSolved { .. }) => ("unsolved", None, None),
+            Err(error) => ("error", None, Some(error.to_string())),
+        };
+        let dict = PyDict::new(py);
+        let items: [(&str, PyObject); 7] = [
+            ("day", self.solver.day().into_py(py)),
+            ("part", self.solver.part().to_string().into_py(py)),
+            ("status", status.into_py(py)),
+            ("answer", answer.into_py(py)),
+            ("error", error.into_py(py)),
+            ("seconds", self.duration.as_secs_f64().into_py(py)),
+            ("cached", self.cached.into_py(py)),
+        ];
+        for (key, value) in items {
+            dict.set_item(key, value)
+                .expect("setting a str key on a new dict does not fail");
+        }
+        dict.into_py(py)
+    }
+}
+
+impl IntoPy<Py<PyAny>> for SchemaToken {
+    fn into_py(self, py: Python<'_>) -> Py<PyAny> {
+        match self {
+            Self::Number(number) => number.into_py(py),
+            Self::Gear => String::from("*").into_py(py),
+            Self::Symbol => String::from("#").into_py(py),
+        }
+    }
+}
+
+#[pymethods]
+impl Tokenizer {
+    #[getter(tokens)]
+    fn py_tokens(&self) -> HashMap<(usize, usize), SchemaToken> {
+        self.tokens()
+    }
+
+    /// the schematic as an int8 ndarray of `[y][x]`, the digits, -1 empty, -2 a symbol and -3 a gear
+    #[cfg(feature = "numpy")]
+    fn to_numpy(&self, py: Python<'_>) -> PyResult<PyObject> {
+        arrays::tokenizer_to_numpy(py, self)
+    }
+}
+
+/// Accepts
+/// - `str`, the puzzle text
+/// - `bytes`, the puzzle text as utf-8
+/// - `os.PathLike`, like `pathlib.Path`
+/// - anything with a `.read()` method returning `str` or `bytes`, like an open file or `io.StringIO`
+impl<'source> FromPyObject<'source> for Input {
+    fn extract(object: &'source PyAny) -> PyResult<Self> {
+        if let Ok(text) = object.downcast::<PyString>() {
+            Ok(Input::Text(text.to_str()?.to_string()))
+        } else if let Ok(bytes) = object.downcast::<PyBytes>() {
+            Ok(Input::Bytes(bytes.as_bytes().to_vec()))
+        } else if object.hasattr("__fspath__")? {
+            Ok(Input::Path(object.extract()?))
+        } else if object.hasattr("read")? {
+            let content = object.call_method0("read")?;
+            if let Ok(text) = content.downcast::<PyString>() {
+                Ok(Input::Text(text.to_str()?.to_string()))
+            } else if let Ok(bytes) = content.downcast::<PyBytes>() {
+                Ok(Input::Bytes(bytes.as_bytes().to_vec()))
+            } else {
+                Err(PyTypeError::new_err(format!(
+                    "read() should return str or bytes, not {}",
+                    content.get_type().name()?
+                )))
+            }
+        } else {
+            Err(PyTypeError::new_err(format!(
+                "input should be str, bytes, os.PathLike or a file-like object, not {}",
+                object.get_type().name()?
+            )))
+        }
+    }
+}
+
+/// json objects become dicts and arrays lists
+fn json_to_py(py: Python<'_>, value: &Value) -> PyObject {
+    match value {
+        Value::Null => py.None(),
+        Value::Bool(boolean) => boolean.into_py(py),
+        Value::Number(number) => {
+            if let Some(number) = number.as_u64() {
+                number.into_py(py)
+            } else if let Some(number) = number.as_i64() {
+                number.into_py(py)
+            } else {
+                number.as_f64().into_py(py)
+            }
+        }
+        Value::String(text) => text.into_py(py),
+        Value::Array(items) => {
+            PyList::new(py, items.iter().map(|item| json_to_py(py, item))).into_py(py)
+        }
+        Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (key, item) in map {
+                dict.set_item(key, json_to_py(py, item))
+                    .expect("setting a str key on a new dict does not fail");
+            }
+            dict.into_py(py)
+        }
+    }
+}
+
+/// reads and solves without holding the GIL, so other python threads can run meanwhile
+fn solve_without_gil(
+    py: Python<'_>,
+    solver: &'static dyn Solver,
+    input: Input,
+) -> PyResult<Answer> {
+    let answer = py.allow_threads(|| {
+        input
+            .read()
+            .and_then(|text| solver::run(solver, &text).result)
+    })?;
+    Ok(answer)
+}
+
+/// lists all registered solvers as `(day, part, name, status)`, status is "solved" or "unsolved"
+#[pyfunction]
+#[pyo3(name = "solvers", text_signature = "()")]
+fn list_solvers() -> Vec<(u8, String, &'static str, &'static str)> {
+    solver::solvers()
+        .iter()
+        .map(|solver| {
+            let status = if solver.solved() {
+                "solved"
+            } else {
+                "unsolved"
+            };
+            (
+                solver.day(),
+                solver.part().to_string(),
+                solver.name(),
+                status,
+            )
+        })
+        .collect()
+}
+
+fn find_solver(day: u8, part: &str) -> PyResult<&'static dyn Solver> {
+    let part: Part = part
+        .parse()
+        .map_err(|_| PyValueError::new_err("part should be 'a' or 'b'"))?;
+    solver::find(day, part)
+        .ok_or_else(|| PyValueError::new_err(format!("no solver for day {} part {}", day, part)))
+}
+
+/// solves `day` `part` of `input`, like `solve(5, "a", "data/day05.txt")`
+#[pyfunction]
+#[pyo3(text_signature = "(day, part, input)")]
+fn solve(py: Python<'_>, day: u8, part: &str, input: Input) -> PyResult<Answer> {
+    solve_without_gil(py, find_solver(day, part)?, input)
+}
+
+/// what every line of `input` adds to the answer of `day` `part`, a dict per line
+#[pyfunction]
+#[pyo3(name = "explain", text_signature = "(day, part, input)")]
+fn explain_lines(py: Python<'_>, day: u8, part: &str, input: Input) -> PyResult<PyObject> {
+    let part: Part = part
+        .parse()
+        .map_err(|_| PyValueError::new_err("part should be 'a' or 'b'"))?;
+    let explanations = py.allow_threads(|| {
+        input
+            .read()
+            .and_then(|text| catch_panic(day, || explain::explain(day, part, &text)))
+    })?;
+
+    let value = serde_json::to_value(explanations).expect("the explanations are valid json");
+    Ok(json_to_py(py, &value))
+}
+
+/// draws `input` of `day` with what the solver decided, `format` is "ansi" or "svg"
+#[pyfunction]
+#[pyo3(
+    name = "render",
+    signature = (day, input, format = "ansi"),
+    text_signature = "(day, input, format='ansi')"
+)]
+fn render_picture(py: Python<'_>, day: u8, input: Input, format: &str) -> PyResult<String> {
+    let format: render::Format = format.parse()?;
+    let picture = py.allow_threads(|| {
+        input
+            .read()
+            .and_then(|text| catch_panic(day, || render::render(day, &text)))
+    })?;
+    Ok(picture.render(format))
+}
+
+/// runs a solver `runs` times, by default on `data/dayNN.txt`, and returns the timings in seconds
+#[pyfunction]
+#[pyo3(
+    name = "benchmark",
+    signature = (day, part, runs = DEFAULT_RUNS, input = None),
+    text_signature = "(day, part, runs=10, input=None)"
+)]
+fn run_benchmark(
+    py: Python<'_>,
+    day: u8,
+    part: &str,
+    runs: usize,
+    input: Option<Input>,
+) -> PyResult<Report> {
+    let solver = find_solver(day, part)?;
+    let input = input.unwrap_or_else(|| Input::for_day(day));
+
+    let report = py.allow_threads(|| {
+        input
+            .read()
+            .and_then(|text| benchmark::benchmark(solver, &text, runs))
+    })?;
+    Ok(report)
+}
+
+/// solves every day in `paths` on a thread pool, returns a dict per solver by its name
+///
+/// `paths` maps the day to its input, days that are not in there are skipped. an unsolved part
+/// or an error only shows in the `status` of that solver, an input that can not be read raises
+#[pyfunction]
+#[pyo3(text_signature = "(paths)")]
+fn run_all(py: Python<'_>, paths: HashMap<u8, Input>) -> PyResult<HashMap<&'static str, Run>> {
+    let runs = py.allow_threads(|| {
+        let inputs = paths
+            .into_par_iter()
+            .map(|(day, input)| Ok((day, input.read()?)))
+            .collect::<Result<HashMap<u8, String>, AocError>>()?;
+        Ok::<_, AocError>(solver::run_all(&inputs))
+    })?;
+
+    Ok(runs
+        .into_iter()
+        .map(|run| (run.solver.name(), run))
+        .collect())
+}
+
+/// checks the solvers against the answers manifest, returns a dict per entry with its `status`
+#[pyfunction]
+#[pyo3(
+    name = "verify",
+    signature = (manifest = PathBuf::from(verify::MANIFEST)),
+    text_signature = "(manifest='data/answers.json')"
+)]
+fn run_verify(py: Python<'_>, manifest: PathBuf) -> PyResult<Vec<Verification>> {
+    let verifications =
+        py.allow_threads(|| verify::load(&manifest).map(|entries| verify::verify(&entries)))?;
+    Ok(verifications)
+}
+
+fn current_cache() -> PyResult<cache::Cache> {
+    cache::current().ok_or_else(|| {
+        AocError::Input(format!(
+            "the cache is off, set {} or call cache_enable",
+            cache::ENV
+        ))
+        .into()
+    })
+}
+
+/// caches the answers of `solve`, `run_all` and the day functions in `directory` from now on
+#[pyfunction]
+#[pyo3(text_signature = "(directory)")]
+fn cache_enable(directory: PathBuf) {
+    cache::enable(directory)
+}
+
+/// turns the cache off, the entries stay on disk
+#[pyfunction]
+#[pyo3(text_signature = "()")]
+fn cache_disable() {
+    cache::disable()
+}
+
+/// the cached answers, a dict per entry
+#[pyfunction]
+#[pyo3(text_signature = "()")]
+fn cache_entries(py: Python<'_>) -> PyResult<PyObject> {
+    let entries = current_cache()?.entries()?;
+    let value = serde_json::to_value(entries).expect("the entries are valid json");
+    Ok(json_to_py(py, &value))
+}
+
+/// removes the cached answers, returns how many there were
+#[pyfunction]
+#[pyo3(text_signature = "()")]
+fn cache_clear() -> PyResult<usize> {
+    Ok(current_cache()?.clear()?)
+}
+
+/// reads the levels of the `logging` loggers again, call it after changing the level of one
+#[pyfunction]
+#[pyo3(text_signature = "()")]
+fn logging_refresh(py: Python<'_>) -> PyResult<()> {
+    logging::refresh(py)
+}
+
+/// a random puzzle input for `day`, the same `seed` and `size` give the same input
+#[pyfunction]
+#[pyo3(name = "generate", text_signature = "(day, seed, size)")]
+fn generate_input(day: u8, seed: u64, size: usize) -> PyResult<String> {
+    Ok(generate::generate(day, seed, size)?)
+}
+
+/// parses a hand like `"3 blue, 4 red"` to `(red, green, blue)`
+#[pyfunction]
+#[pyo3(text_signature = "(input)")]
+fn day02_parse_hand(input: &str) -> PyResult<HandTuple> {
+    Ok(catch_panic(2, || day02::day02_parse_hand(input))?)
+}
+
+/// parses a game like `"Game 1: 3 blue; 4 red"` to `(id, [(red, green, blue), ...])`
+#[pyfunction]
+#[pyo3(text_signature = "(input)")]
+fn day02_parse_game(input: &str) -> PyResult<(usize, Vec<HandTuple>)> {
+    Ok(catch_panic(2, || day02::day02_parse_game(input))?)
+}
+
+/// parses the engine schematic, see `Tokenizer.tokens`
+#[pyfunction]
+#[pyo3(text_signature = "(schema)")]
+fn day03a_parse_tokens(schema: &str) -> PyResult<Tokenizer> {
+    Ok(catch_panic(3, || day03::day03a_parse_tokens(schema))?)
+}
+
+/// the hands of the plays, sorted from weakest to strongest
+#[pyfunction]
+#[pyo3(text_signature = "(cards_text)")]
+fn day07_sort_cards(cards_text: &str) -> PyResult<Vec<Vec<usize>>> {
+    Ok(catch_panic(7, || day07::day07_sort_cards(cards_text))?)
+}
+
+/// A Python module implemented in Rust.
+#[pymodule]
+fn aoc2023(py: Python, m: &PyModule) -> PyResult<()> {
+    logging::install(py)?;
+    if let Some(directory) = std::env::var_os(cache::ENV) {
+        cache::enable(directory);
+    }
+
+    for solver in solver::solvers() {
+        // the docstring has to live as long as the function, the module is only created once
+        let doc: &'static str = Box::leak(
+            format!(
+                "{}(input)\n--\n\nsolves day {} part {}, input is the puzzle text, a path or a file",
+                solver.name(),
+                solver.day(),
+                solver.part()
+            )
+            .into_boxed_str(),
+        );
+        let function =
+            PyCFunction::new_closure(py, Some(solver.name()), Some(doc), move |args, _kwargs| {
+                let (input,): (Input,) = args.extract()?;
+                solve_without_gil(args.py(), *solver, input)
+            })?;
+        m.add(solver.name(), function)?;
+    }
+
+    for &day in model::DAYS {
+        let name = format!("day{:02}_parse", day);
+        let doc: &'static str = Box::leak(
+            format!(
+                "{}(input)\n--\n\nthe parsed puzzle of day {} as a dict, input is the puzzle text, a path or a file",
+                name, day
+            )
+            .into_boxed_str(),
+        );
+        let function = PyCFunction::new_closure(
+            py,
+            Some(Box::leak(name.clone().into_boxed_str())),
+            Some(doc),
+            move |args, _kwargs| {
+                let (input,): (Input,) = args.extract()?;
+                let value = args.py().allow_threads(|| {
+                    input
+                        .read()
+                        .and_then(|text| catch_panic(day, || model::parse(day, &text)))
+                })?;
+                Ok::<_, PyErr>(json_to_py(args.py(), &value))
+            },
+        )?;
+        m.add(&name, function)?;
+    }
+
+    m.add("AocError", py.get_type::<exceptions::AocError>())?;
+    m.add("ParseError", py.get_type::<exceptions::ParseError>())?;
+    m.add("InputError", py.get_type::<exceptions::InputError>())?;
+    m.add(
+        "UnsolvableError",
+        py.get_type::<exceptions::UnsolvableError>(),
+    )?;
+    m.add(
+        "NotSolvedError",
+        py.get_type::<exceptions::NotSolvedError>(),
+    )?;
+    m.add("PanicError", py.get_type::<exceptions::PanicError>())?;
+
+    m.add_function(wrap_pyfunction!(list_solvers, m)?)?;
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(run_all, m)?)?;
+    m.add_function(wrap_pyfunction!(run_benchmark, m)?)?;
+    m.add_function(wrap_pyfunction!(run_verify, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_input, m)?)?;
+    m.add_function(wrap_pyfunction!(explain_lines, m)?)?;
+    m.add_function(wrap_pyfunction!(render_picture, m)?)?;
+    m.add_function(wrap_pyfunction!(cache_enable, m)?)?;
+    m.add_function(wrap_pyfunction!(cache_disable, m)?)?;
+    m.add_function(wrap_pyfunction!(cache_entries, m)?)?;
+    m.add_function(wrap_pyfunction!(cache_clear, m)?)?;
+    m.add_function(wrap_pyfunction!(logging_refresh, m)?)?;
+    m.add_function(wrap_pyfunction!(day02_parse_hand, m)?)?;
+    m.add_function(wrap_pyfunction!(day02_parse_game, m)?)?;
+    m.add_class::<Tokenizer>()?;
+    m.add_function(wrap_pyfunction!(day03a_parse_tokens, m)?)?;
+    m.add_function(wrap_pyfunction!(day07_sort_cards, m)?)?;
+
+    #[cfg(feature = "numpy")]
+    {
+        m.add_function(wrap_pyfunction!(arrays::to_numpy, m)?)?;
+        m.add_function(wrap_pyfunction!(arrays::day06_ways_to_win, m)?)?;
+        m.add_function(wrap_pyfunction!(arrays::day09_extrapolate, m)?)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,100 @@
+//! NumPy arrays of the grid and numeric days, only with the `numpy` feature.
+//!
+//! The arrays are built in rust and handed over as ndarrays, python reads them through the buffer
+//! protocol without copying. See [`crate::arrays`] for what the cells of every day are.
+
+use crate::arrays::{self, Array};
+use crate::day03::Tokenizer;
+use crate::error::catch_panic;
+use crate::grid::Grid;
+use crate::input::Input;
+use crate::solver::Part;
+use crate::{day06, day09};
+use numpy::ndarray::Array2;
+use numpy::{Element, IntoPyArray, PyArray1, PyReadonlyArray1, PyReadonlyArray2};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// the numpy bindings panic when numpy is not installed, this is an `ImportError` instead
+fn import_numpy(py: Python<'_>) -> PyResult<()> {
+    py.import("numpy")?;
+    Ok(())
+}
+
+fn grid_to_numpy<T: Element>(py: Python<'_>, grid: Grid<T>) -> PyObject {
+    let shape = (grid.height(), grid.width());
+    Array2::from_shape_vec(shape, grid.into_cells())
+        .expect("a grid has width times height cells")
+        .into_pyarray(py)
+        .into_py(py)
+}
+
+impl IntoPy<PyObject> for Array {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Array::Int8(grid) => grid_to_numpy(py, grid),
+            Array::UInt8(grid) => grid_to_numpy(py, grid),
+            Array::Bool(grid) => grid_to_numpy(py, grid),
+            Array::Int64(grid) => grid_to_numpy(py, grid),
+        }
+    }
+}
+
+pub(super) fn tokenizer_to_numpy(py: Python<'_>, tokenizer: &Tokenizer) -> PyResult<PyObject> {
+    import_numpy(py)?;
+    Ok(grid_to_numpy(py, tokenizer.codes()))
+}
+
+/// the puzzle of `day` as a 2d ndarray, for the days 3, 6, 9, 10, 11 and 12
+#[pyfunction]
+#[pyo3(text_signature = "(day, input)")]
+pub(super) fn to_numpy(py: Python<'_>, day: u8, input: Input) -> PyResult<Array> {
+    import_numpy(py)?;
+    let array = py.allow_threads(|| {
+        input
+            .read()
+            .and_then(|text| catch_panic(day, || arrays::to_array(day, &text)))
+    })?;
+    Ok(array)
+}
+
+/// the amount of ways to win every race, `times` and `distances` are int64 arrays as long
+#[pyfunction]
+#[pyo3(text_signature = "(times, distances)")]
+pub(super) fn day06_ways_to_win<'py>(
+    py: Python<'py>,
+    times: &PyAny,
+    distances: &PyAny,
+) -> PyResult<&'py PyArray1<i64>> {
+    import_numpy(py)?;
+    let times: PyReadonlyArray1<i64> = times.extract()?;
+    let distances: PyReadonlyArray1<i64> = distances.extract()?;
+    let times = times.as_array().to_vec();
+    let distances = distances.as_array().to_vec();
+    let ways =
+        py.allow_threads(|| catch_panic(6, || day06::day06_ways_to_win(&times, &distances)))?;
+    Ok(ways.into_pyarray(py))
+}
+
+/// the next number of every row of `histories` for part "a", the previous one for part "b"
+#[pyfunction]
+#[pyo3(signature = (histories, part = "a"), text_signature = "(histories, part='a')")]
+pub(super) fn day09_extrapolate<'py>(
+    py: Python<'py>,
+    histories: &PyAny,
+    part: &str,
+) -> PyResult<&'py PyArray1<i64>> {
+    import_numpy(py)?;
+    let histories: PyReadonlyArray2<i64> = histories.extract()?;
+    let part: Part = part
+        .parse()
+        .map_err(|_| PyValueError::new_err("part should be 'a' or 'b'"))?;
+    let histories: Vec<Vec<i64>> = histories
+        .as_array()
+        .rows()
+        .into_iter()
+        .map(|row| row.to_vec())
+        .collect();
+    let next = py.allow_threads(|| catch_panic(9, || day09::day09_extrapolate(histories, part)))?;
+    Ok(next.into_pyarray(py))
+}
@@ -0,0 +1,183 @@
+//! Forwards the `tracing` events of the solvers to the python `logging` module.
+//!
+//! The target becomes the logger name, so the events of `aoc2023::day05` go to
+//! `logging.getLogger("aoc2023.day05")`. `TRACE` is level 5, below `DEBUG`.
+//!
+//! The levels of the loggers are read once when the module is imported, and again by
+//! `aoc2023.logging_refresh()`. An event below the level of its logger is skipped by `tracing`
+//! itself, it does not take the GIL, so a change of level from python needs a refresh to be seen.
+//! A logger made after the last read has the level of its closest parent until then.
+
+use lazy_static::lazy_static;
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+use std::sync::RwLock;
+use tracing::field::{Field, Visit};
+use tracing::level_filters::LevelFilter;
+use tracing::subscriber::Interest;
+use tracing::{Event, Level, Metadata, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+pub const TRACE: u8 = 5;
+
+lazy_static! {
+    /// the most verbose level every logger lets through, by the name of the logger
+    static ref LEVELS: RwLock<HashMap<String, LevelFilter>> = RwLock::new(HashMap::new());
+}
+
+fn python_level(level: &Level) -> u8 {
+    match *level {
+        Level::TRACE => TRACE,
+        Level::DEBUG => 10,
+        Level::INFO => 20,
+        Level::WARN => 30,
+        Level::ERROR => 40,
+    }
+}
+
+fn logger_name(target: &str) -> String {
+    target.replace("::", ".")
+}
+
+fn logger<'py>(py: Python<'py>, target: &str) -> PyResult<&'py PyAny> {
+    py.import("logging")?
+        .call_method1("getLogger", (logger_name(target),))
+}
+
+/// the most verbose level `logger` is enabled for, `OFF` when it logs nothing
+fn level_filter(logger: &PyAny) -> PyResult<LevelFilter> {
+    for level in [
+        Level::TRACE,
+        Level::DEBUG,
+        Level::INFO,
+        Level::WARN,
+        Level::ERROR,
+    ] {
+        if logger
+            .call_method1("isEnabledFor", (python_level(&level),))?
+            .extract()?
+        {
+            return Ok(LevelFilter::from_level(level));
+        }
+    }
+    Ok(LevelFilter::OFF)
+}
+
+/// the level of the logger of `target`, or of its closest parent when python had not made that
+/// logger at the last read. never takes the GIL, `tracing` calls this while it holds its
+/// callsite lock, and [`refresh`] takes that lock too
+fn target_level(target: &str) -> LevelFilter {
+    let levels = LEVELS.read().expect("the levels lock is not poisoned");
+    let mut name = logger_name(target);
+
+    loop {
+        if let Some(level) = levels.get(&name) {
+            return *level;
+        }
+        match name.rfind('.') {
+            Some(dot) => name.truncate(dot),
+            None if !name.is_empty() => name.clear(),
+            None => return LevelFilter::OFF,
+        }
+    }
+}
+
+/// reads the level of the root logger and of every logger python has made so far
+fn read_levels(py: Python<'_>) -> PyResult<()> {
+    let logging = py.import("logging")?;
+    let mut levels = HashMap::new();
+    levels.insert(String::new(), level_filter(logging.getattr("root")?)?);
+
+    let loggers = logging
+        .getattr("root")?
+        .getattr("manager")?
+        .getattr("loggerDict")?
+        .call_method0("items")?;
+    for item in loggers.iter()? {
+        let (name, logger): (String, &PyAny) = item?.extract()?;
+        // the dict also has placeholders for the parents of a logger that does not exist yet
+        if logger.hasattr("isEnabledFor")? {
+            levels.insert(name, level_filter(logger)?);
+        }
+    }
+
+    *LEVELS.write().expect("the levels lock is not poisoned") = levels;
+    Ok(())
+}
+
+/// the message first, the other fields after it as `name=value`
+#[derive(Default)]
+struct Message(String);
+
+impl Visit for Message {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        let result = if field.name() == "message" {
+            let fields = std::mem::take(&mut self.0);
+            write!(self.0, "{:?}{}", value, fields)
+        } else {
+            write!(self.0, " {}={:?}", field.name(), value)
+        };
+        result.expect("writing to a string does not fail");
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record_debug(field, &format_args!("{}", value))
+    }
+}
+
+pub struct PythonLogging;
+
+impl<S: Subscriber> Layer<S> for PythonLogging {
+    fn register_callsite(&self, metadata: &'static Metadata<'static>) -> Interest {
+        if *metadata.level() <= target_level(metadata.target()) {
+            Interest::always()
+        } else {
+            Interest::never()
+        }
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>, _ctx: Context<'_, S>) -> bool {
+        *metadata.level() <= target_level(metadata.target())
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        // a logger made after the last refresh has the level of a parent, which is in here
+        let levels = LEVELS.read().expect("the levels lock is not poisoned");
+        Some(levels.values().copied().max().unwrap_or(LevelFilter::OFF))
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut message = Message::default();
+        event.record(&mut message);
+        let metadata = event.metadata();
+
+        Python::with_gil(|py| {
+            let logged = logger(py, metadata.target()).and_then(|logger| {
+                logger.call_method1("log", (python_level(metadata.level()), message.0))
+            });
+            if let Err(error) = logged {
+                error.print(py);
+            }
+        })
+    }
+}
+
+/// makes [`PythonLogging`] the global subscriber, does nothing if there already is one
+pub fn install(py: Python<'_>) -> PyResult<()> {
+    py.import("logging")?
+        .call_method1("addLevelName", (TRACE, "TRACE"))?;
+    read_levels(py)?;
+
+    let subscriber = tracing_subscriber::registry().with(PythonLogging);
+    let _ = tracing::subscriber::set_global_default(subscriber);
+    Ok(())
+}
+
+/// reads the levels of the python loggers again, after they were changed
+pub fn refresh(py: Python<'_>) -> PyResult<()> {
+    read_levels(py)?;
+    // the rebuild waits for the callsite lock, another thread can hold it and want the GIL
+    py.allow_threads(tracing::callsite::rebuild_interest_cache);
+    Ok(())
+}
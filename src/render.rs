@@ -0,0 +1,375 @@
+//! Draws the grid days with what the solver decided, as colored terminal text or as SVG.
+//!
+//! A [`Picture`] is the puzzle text with a [`Mark`] on the cells that matter, like the numbers
+//! that count as part numbers or the tiles of the pipe loop. The days describe what their marks
+//! mean, that becomes the legend under the picture.
+
+use crate::error::AocError;
+use crate::grid::{Grid, Position};
+use crate::{day03, day10, day11};
+use serde::Serialize;
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+/// the size of a cell in the SVG, in pixels
+const CELL_WIDTH: usize = 10;
+const CELL_HEIGHT: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mark {
+    /// adds to the answer, like a part number or a tile of the loop
+    Counted,
+    /// looked at but left out, like a number without a symbol around it
+    Ignored,
+    /// what decides whether the others count, like a symbol
+    Anchor,
+    /// stands out from the rest, like a gear or the start of the loop
+    Special,
+    /// empty space that matters, like the rows without galaxies
+    Space,
+}
+
+impl Mark {
+    /// the SGR parameters of the terminal color
+    fn ansi(self) -> &'static str {
+        match self {
+            Mark::Counted => "1;32",
+            Mark::Ignored => "31",
+            Mark::Anchor => "1;33",
+            Mark::Special => "1;35",
+            Mark::Space => "34",
+        }
+    }
+
+    /// the background of the cell in the SVG
+    fn svg(self) -> &'static str {
+        match self {
+            Mark::Counted => "#a5d6a7",
+            Mark::Ignored => "#ef9a9a",
+            Mark::Anchor => "#ffe082",
+            Mark::Special => "#ce93d8",
+            Mark::Space => "#bbdefb",
+        }
+    }
+}
+
+impl fmt::Display for Mark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Mark::Counted => "counted",
+            Mark::Ignored => "ignored",
+            Mark::Anchor => "anchor",
+            Mark::Special => "special",
+            Mark::Space => "space",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// the puzzle text with terminal colors
+    Ansi,
+    /// a standalone SVG image
+    Svg,
+}
+
+impl FromStr for Format {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ansi" => Ok(Format::Ansi),
+            "svg" => Ok(Format::Svg),
+            _ => Err(AocError::Input(format!(
+                "format should be 'ansi' or 'svg', not {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Picture {
+    pub day: u8,
+    cells: Grid<char>,
+    marks: Grid<Option<Mark>>,
+    /// what the marks mean, in the order they were described
+    legend: Vec<(Mark, String)>,
+}
+
+impl Picture {
+    pub fn new(day: u8, cells: Grid<char>) -> Picture {
+        let marks = Grid::filled(cells.width(), cells.height(), None);
+        Picture {
+            day,
+            cells,
+            marks,
+            legend: Vec::new(),
+        }
+    }
+
+    /// marks the cell at `position`, a later mark replaces an earlier one
+    pub fn mark(&mut self, position: Position, mark: Mark) {
+        self.marks[position] = Some(mark);
+    }
+
+    /// adds `mark` to the legend, describing it again replaces the description
+    pub fn describe(&mut self, mark: Mark, description: &str) {
+        match self.legend.iter_mut().find(|(marked, _)| *marked == mark) {
+            Some((_, text)) => *text = description.to_string(),
+            None => self.legend.push((mark, description.to_string())),
+        }
+    }
+
+    pub fn cells(&self) -> &Grid<char> {
+        &self.cells
+    }
+
+    pub fn mark_at(&self, position: Position) -> Option<Mark> {
+        self.marks.get(position).copied().flatten()
+    }
+
+    /// the positions with `mark`, row by row
+    pub fn marked(&self, mark: Mark) -> Vec<Position> {
+        self.marks
+            .iter()
+            .filter(|(_, marked)| **marked == Some(mark))
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    pub fn legend(&self) -> &[(Mark, String)] {
+        &self.legend
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Ansi => self.to_ansi(),
+            Format::Svg => self.to_svg(),
+        }
+    }
+
+    /// the puzzle text with a color per mark, then the legend
+    pub fn to_ansi(&self) -> String {
+        let mut text = String::new();
+
+        for (y, row) in self.cells.rows().enumerate() {
+            let mut current = None;
+            for (x, ch) in row.iter().enumerate() {
+                // only switches color where the mark changes, to keep the output small
+                let mark = self.mark_at((x, y));
+                if mark != current {
+                    if current.is_some() {
+                        text.push_str("\x1b[0m");
+                    }
+                    if let Some(mark) = mark {
+                        write!(text, "\x1b[{}m", mark.ansi())
+                            .expect("writing to a string does not fail");
+                    }
+                    current = mark;
+                }
+                text.push(*ch);
+            }
+            if current.is_some() {
+                text.push_str("\x1b[0m");
+            }
+            text.push('\n');
+        }
+
+        if !self.legend.is_empty() {
+            text.push('\n');
+        }
+        for (mark, description) in &self.legend {
+            writeln!(
+                text,
+                "\x1b[{}m{}\x1b[0m: {}",
+                mark.ansi(),
+                mark,
+                description
+            )
+            .expect("writing to a string does not fail");
+        }
+
+        text
+    }
+
+    /// a cell per character with its mark as background, the legend below the grid
+    pub fn to_svg(&self) -> String {
+        let width = (self.cells.width() * CELL_WIDTH).max(CELL_WIDTH * 40);
+        let grid_height = self.cells.height() * CELL_HEIGHT;
+        let height = grid_height + (self.legend.len() + 1) * CELL_HEIGHT;
+
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" font-family=\"monospace\" font-size=\"14\">"
+        )
+        .expect("writing to a string does not fail");
+        writeln!(svg, "<title>day {:02}</title>", self.day)
+            .expect("writing to a string does not fail");
+        writeln!(
+            svg,
+            "<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>"
+        )
+        .expect("writing to a string does not fail");
+
+        for ((x, y), mark) in self.marks.iter() {
+            if let Some(mark) = mark {
+                writeln!(
+                    svg,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                    x * CELL_WIDTH,
+                    y * CELL_HEIGHT,
+                    CELL_WIDTH,
+                    CELL_HEIGHT,
+                    mark.svg()
+                )
+                .expect("writing to a string does not fail");
+            }
+        }
+
+        for ((x, y), ch) in self.cells.iter() {
+            if !ch.is_whitespace() {
+                writeln!(
+                    svg,
+                    "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>",
+                    x * CELL_WIDTH + CELL_WIDTH / 2,
+                    (y + 1) * CELL_HEIGHT - 4,
+                    escape(*ch)
+                )
+                .expect("writing to a string does not fail");
+            }
+        }
+
+        for (index, (mark, description)) in self.legend.iter().enumerate() {
+            let y = grid_height + (index + 1) * CELL_HEIGHT;
+            writeln!(
+                svg,
+                "<rect x=\"0\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\
+                 <text x=\"{}\" y=\"{}\">{}: {}</text>",
+                y,
+                CELL_WIDTH,
+                CELL_HEIGHT,
+                mark.svg(),
+                CELL_WIDTH * 2,
+                y + CELL_HEIGHT - 4,
+                mark,
+                description.chars().map(escape).collect::<String>()
+            )
+            .expect("writing to a string does not fail");
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+fn escape(ch: char) -> String {
+    match ch {
+        '&' => String::from("&amp;"),
+        '<' => String::from("&lt;"),
+        '>' => String::from("&gt;"),
+        '"' => String::from("&quot;"),
+        ch => ch.to_string(),
+    }
+}
+
+/// the days that can be drawn
+pub const RENDERED: &[u8] = &[3, 10, 11];
+
+/// `input` of `day` with what the solver decided marked
+pub fn render(day: u8, input: &str) -> Result<Picture, AocError> {
+    match day {
+        3 => day03::day03_render(input),
+        10 => day10::day10_render(input),
+        11 => day11::day11_render(input),
+        _ => Err(AocError::Input(format!("day {} can not be drawn", day))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(day: u8) -> String {
+        std::fs::read_to_string(format!("data/day{:02}_debug.txt", day)).unwrap()
+    }
+
+    #[test]
+    fn every_sample() {
+        for &day in RENDERED {
+            let picture = render(day, &sample(day)).unwrap();
+            assert!(!picture.legend().is_empty(), "day {}", day);
+
+            let svg = picture.to_svg();
+            assert!(svg.starts_with("<svg "), "day {}", day);
+            assert!(svg.ends_with("</svg>\n"), "day {}", day);
+        }
+    }
+
+    #[test]
+    fn day03_part_numbers() {
+        let picture = render(3, "467..114\n...*....\n..35.&..").unwrap();
+        assert_eq!(
+            vec![(0, 0), (1, 0), (2, 0), (2, 2), (3, 2)],
+            picture.marked(Mark::Counted)
+        );
+        assert_eq!(vec![(5, 0), (6, 0), (7, 0)], picture.marked(Mark::Ignored));
+        assert_eq!(vec![(5, 2)], picture.marked(Mark::Anchor));
+        assert_eq!(vec![(3, 1)], picture.marked(Mark::Special));
+    }
+
+    #[test]
+    fn day10_loop() {
+        let picture = render(10, "-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF").unwrap();
+        assert_eq!(vec![(1, 1)], picture.marked(Mark::Special));
+        assert_eq!(7, picture.marked(Mark::Counted).len());
+        assert_eq!(Some(Mark::Ignored), picture.mark_at((0, 0)));
+        assert_eq!('┌', picture.cells()[(3, 0)]);
+    }
+
+    #[test]
+    fn day11_expansion() {
+        let picture = render(11, "#..\n...\n..#").unwrap();
+        assert_eq!(vec![(0, 0), (2, 2)], picture.marked(Mark::Special));
+        assert_eq!(
+            vec![(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+            picture.marked(Mark::Space)
+        );
+    }
+
+    #[test]
+    fn ansi() {
+        let mut picture = Picture::new(3, "ab.\n...".parse().unwrap());
+        picture.mark((0, 0), Mark::Counted);
+        picture.mark((1, 0), Mark::Counted);
+        picture.mark((2, 1), Mark::Ignored);
+        picture.describe(Mark::Counted, "a letter");
+
+        assert_eq!(
+            "\x1b[1;32mab\x1b[0m.\n..\x1b[31m.\x1b[0m\n\n\x1b[1;32mcounted\x1b[0m: a letter\n",
+            picture.to_ansi()
+        );
+    }
+
+    #[test]
+    fn svg_escapes() {
+        let mut picture = Picture::new(3, "&<".parse().unwrap());
+        picture.describe(Mark::Anchor, "a <symbol>");
+        let svg = picture.to_svg();
+
+        assert!(svg.contains(">&amp;</text>"));
+        assert!(svg.contains(">&lt;</text>"));
+        assert!(svg.contains("anchor: a &lt;symbol&gt;"));
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(Ok(Format::Svg), "svg".parse());
+        assert!(matches!("png".parse::<Format>(), Err(AocError::Input(_))));
+        assert!(matches!(render(5, ""), Err(AocError::Input(_))));
+    }
+}
@@ -0,0 +1,414 @@
+//! Scaffolds a new day, `aoc2023 new-day --day 13`.
+//!
+//! The day gets a module with its tests, placeholders in `data/`, a python test and a fuzz target.
+//! Files that exist are kept, so running it again is harmless. The modules, the solver registry
+//! and the parsers of every day are in [`DAYS_PATH`], it is generated from the `src/dayNN.rs`
+//! files so nothing gets patched into `lib.rs`.
+
+use crate::error::AocError;
+use crate::solver::{self, Part};
+use std::fmt::{self, Write};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+pub const DAYS_PATH: &str = "src/days.rs";
+
+const DAYS_HEADER: &str = "\
+// generated by `aoc2023 new-day` from the `src/dayNN.rs` files. swap `unsolved!` for `solver!`
+// once a part is solved, other changes are overwritten by the next new day
+";
+
+const MODULE: &str = r#"use crate::error::AocError;
+use crate::input;
+use crate::solver::Part;
+use serde::Serialize;
+
+const DAY: u8 = {number};
+
+#[derive(Debug, Serialize)]
+pub struct Puzzle {
+    lines: Vec<String>,
+}
+
+pub fn day{day}_parse(input: &str) -> Result<Puzzle, AocError> {
+    let input = &input::normalize(input);
+    Ok(Puzzle {
+        lines: input.lines().map(String::from).collect(),
+    })
+}
+
+pub fn day{day}a(_input: &str) -> Result<usize, AocError> {
+    Err(AocError::not_solved(DAY, Part::A))
+}
+
+pub fn day{day}b(_input: &str) -> Result<usize, AocError> {
+    Err(AocError::not_solved(DAY, Part::B))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../data/day{day}_debug.txt");
+{rust_tests}}
+"#;
+
+const FUZZ_TARGET: &str = "#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| aoc2023_fuzz::check({number}, input));
+";
+
+const FUZZ_BIN: &str = r#"
+[[bin]]
+name = "day{day}"
+path = "fuzz_targets/day{day}.rs"
+test = false
+doc = false
+bench = false
+"#;
+
+/// what happened to a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Created => write!(f, "created"),
+            Change::Updated => write!(f, "updated"),
+            Change::Unchanged => write!(f, "unchanged"),
+        }
+    }
+}
+
+/// a day to scaffold, the answers are those of the sample in the puzzle text
+#[derive(Debug, Clone, Default)]
+pub struct NewDay {
+    pub day: u8,
+    pub sample: Option<String>,
+    pub answer_a: Option<usize>,
+    pub answer_b: Option<usize>,
+}
+
+impl NewDay {
+    pub fn new(day: u8) -> NewDay {
+        NewDay {
+            day,
+            ..NewDay::default()
+        }
+    }
+
+    fn fill(&self, template: &str) -> String {
+        template
+            .replace("{day}", &format!("{:02}", self.day))
+            .replace("{number}", &self.day.to_string())
+    }
+
+    fn answers(&self) -> [(Part, Option<usize>); 2] {
+        [(Part::A, self.answer_a), (Part::B, self.answer_b)]
+    }
+
+    fn module(&self) -> String {
+        let mut tests = String::new();
+        for (part, answer) in self.answers() {
+            let (attribute, expected) = match answer {
+                Some(answer) => (
+                    "    #[ignore = \"not solved yet\"]\n",
+                    format!("Ok({})", answer),
+                ),
+                None => (
+                    "",
+                    format!("Err(AocError::not_solved(DAY, Part::{:?}))", part),
+                ),
+            };
+            write!(
+                tests,
+                "\n    #[test]\n{}    fn sample_{}() {{\n        assert_eq!({}, day{{day}}{}(SAMPLE));\n    }}\n",
+                attribute, part, expected, part
+            )
+            .expect("writing to a string does not fail");
+        }
+
+        self.fill(&MODULE.replace("{rust_tests}", &tests))
+    }
+
+    fn python_test(&self) -> String {
+        let mut test = String::from("import pathlib\n\nimport aoc2023\nimport pytest\n");
+        for (part, answer) in self.answers() {
+            let body = match answer {
+                Some(answer) => format!(
+                    "@pytest.mark.skip(reason=\"not solved yet\")\ndef test_day{{day}}{part}():\n    assert {answer} == aoc2023.day{{day}}{part}(pathlib.Path(\"data/day{{day}}_debug.txt\"))\n",
+                    part = part,
+                    answer = answer
+                ),
+                None => format!(
+                    "def test_day{{day}}{part}():\n    with pytest.raises(aoc2023.NotSolvedError):\n        aoc2023.day{{day}}{part}(pathlib.Path(\"data/day{{day}}_debug.txt\"))\n",
+                    part = part
+                ),
+            };
+            test.push_str("\n\n");
+            test.push_str(&body);
+        }
+
+        self.fill(&test)
+    }
+
+    /// scaffolds the day in the repository at `root`, returns the files and what happened to them
+    pub fn create(&self, root: &Path) -> Result<Vec<(PathBuf, Change)>, AocError> {
+        if !(1..=25).contains(&self.day) {
+            return Err(AocError::Input(format!(
+                "day should be between 1 and 25, not {}",
+                self.day
+            )));
+        }
+
+        let fuzz_manifest = root.join("fuzz/Cargo.toml");
+        let manifest = fs::read_to_string(&fuzz_manifest)
+            .map_err(|error| AocError::Input(format!("{}: {}", fuzz_manifest.display(), error)))?;
+
+        let day = format!("{:02}", self.day);
+        let mut changes = vec![
+            create(&root.join(format!("src/day{}.rs", day)), &self.module())?,
+            create(&root.join(format!("data/day{}.txt", day)), "")?,
+            self.create_sample(&root.join(format!("data/day{}_debug.txt", day)))?,
+            create(
+                &root.join(format!("test/day{}_test.py", day)),
+                &self.python_test(),
+            )?,
+            create(
+                &root.join(format!("fuzz/fuzz_targets/day{}.rs", day)),
+                &self.fill(FUZZ_TARGET),
+            )?,
+        ];
+
+        let change = if manifest.contains(&format!("name = \"day{}\"", day)) {
+            Change::Unchanged
+        } else {
+            fs::write(&fuzz_manifest, manifest + &self.fill(FUZZ_BIN))?;
+            Change::Updated
+        };
+        changes.push((fuzz_manifest, change));
+
+        let days_path = root.join(DAYS_PATH);
+        let source = days_source(&days(root)?);
+        let change = match fs::read_to_string(&days_path) {
+            Ok(existing) if existing == source => Change::Unchanged,
+            Ok(_) => Change::Updated,
+            Err(error) if error.kind() == ErrorKind::NotFound => Change::Created,
+            Err(error) => return Err(error.into()),
+        };
+        if change != Change::Unchanged {
+            fs::write(&days_path, source)?;
+        }
+        changes.push((days_path, change));
+
+        Ok(changes)
+    }
+
+    /// an empty sample is filled in, one with text is kept
+    fn create_sample(&self, path: &Path) -> Result<(PathBuf, Change), AocError> {
+        let sample = self.sample.as_deref().unwrap_or_default();
+        match fs::read_to_string(path) {
+            Ok(existing) if existing.is_empty() && !sample.is_empty() => {
+                fs::write(path, sample)?;
+                Ok((path.to_path_buf(), Change::Updated))
+            }
+            Ok(_) => Ok((path.to_path_buf(), Change::Unchanged)),
+            Err(error) if error.kind() == ErrorKind::NotFound => create(path, sample),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+/// writes `content` to `path` unless it exists
+fn create(path: &Path, content: &str) -> Result<(PathBuf, Change), AocError> {
+    if path.exists() {
+        return Ok((path.to_path_buf(), Change::Unchanged));
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)?;
+    Ok((path.to_path_buf(), Change::Created))
+}
+
+/// the days that have a `src/dayNN.rs`, sorted
+pub fn days(root: &Path) -> Result<Vec<u8>, AocError> {
+    let mut days = Vec::new();
+    for entry in fs::read_dir(root.join("src"))? {
+        let name = entry?.file_name();
+        let day = name
+            .to_str()
+            .and_then(|name| name.strip_prefix("day"))
+            .and_then(|name| name.strip_suffix(".rs"))
+            .filter(|number| number.len() == 2)
+            .and_then(|number| number.parse::<u8>().ok());
+        days.extend(day);
+    }
+    days.sort_unstable();
+    Ok(days)
+}
+
+/// the content of [`DAYS_PATH`]. whether a part is solved comes from the current registry, a day
+/// that is not in it yet is unsolved
+pub fn days_source(days: &[u8]) -> String {
+    let mut source = String::from(DAYS_HEADER);
+
+    source.push('\n');
+    for day in days {
+        writeln!(source, "pub mod day{:02};", day).expect("writing to a string does not fail");
+    }
+
+    source.push_str(
+        "
+mod registry {
+    use crate::error::AocError;
+    use crate::model::to_value;
+    use crate::solver::{solver, unsolved, Solver};
+    use serde_json::Value;
+
+    /// the days with a module
+    pub(crate) const DAYS: &[u8] = &[",
+    );
+    let numbers: Vec<String> = days.iter().map(u8::to_string).collect();
+    source.push_str(&numbers.join(", "));
+    source.push_str("];\n\n    pub(crate) static SOLVERS: &[&dyn Solver] = &[\n");
+
+    for day in days {
+        for part in [Part::A, Part::B] {
+            let solved = solver::find(*day, part).is_some_and(|solver| solver.solved());
+            writeln!(
+                source,
+                "        {}!({}, {:?}, day{:02}::day{:02}{}),",
+                if solved { "solver" } else { "unsolved" },
+                day,
+                part,
+                day,
+                day,
+                part
+            )
+            .expect("writing to a string does not fail");
+        }
+    }
+
+    source.push_str(
+        "    ];
+
+    /// parses `input` as the puzzle of `day`
+    pub(crate) fn parse(day: u8, input: &str) -> Result<Value, AocError> {
+        match day {
+",
+    );
+    for day in days {
+        writeln!(
+            source,
+            "            {} => to_value(day, crate::day{:02}::day{:02}_parse(input)),",
+            day, day, day
+        )
+        .expect("writing to a string does not fail");
+    }
+    source.push_str(
+        "            _ => Err(AocError::Input(format!(\"no parser for day {}\", day))),
+        }
+    }
+}
+",
+    );
+
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_in_days_are_up_to_date() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        assert_eq!(
+            include_str!("days.rs"),
+            days_source(&days(root).unwrap()),
+            "run `aoc2023 new-day` to regenerate {}",
+            DAYS_PATH
+        );
+    }
+
+    #[test]
+    fn module_tests() {
+        let mut new_day = NewDay::new(13);
+        new_day.answer_b = Some(400);
+        let module = new_day.module();
+
+        assert!(module.contains("pub fn day13a(_input: &str)"));
+        assert!(module.contains("include_str!(\"../data/day13_debug.txt\")"));
+        assert!(module.contains(
+            "    fn sample_a() {\n        assert_eq!(Err(AocError::not_solved(DAY, Part::A)), day13a(SAMPLE));"
+        ));
+        assert!(module.contains(
+            "    #[ignore = \"not solved yet\"]\n    fn sample_b() {\n        assert_eq!(Ok(400), day13b(SAMPLE));"
+        ));
+    }
+
+    #[test]
+    fn python_tests() {
+        let mut new_day = NewDay::new(13);
+        new_day.answer_b = Some(400);
+        let test = new_day.python_test();
+
+        assert!(test.starts_with("import pathlib\n\nimport aoc2023\nimport pytest\n"));
+        assert!(test.contains(
+            "    with pytest.raises(aoc2023.NotSolvedError):\n        aoc2023.day13a(pathlib.Path(\"data/day13_debug.txt\"))\n"
+        ));
+        assert!(test.contains(
+            "    assert 400 == aoc2023.day13b(pathlib.Path(\"data/day13_debug.txt\"))\n"
+        ));
+    }
+
+    #[test]
+    fn create_twice() {
+        let root = std::env::temp_dir().join(format!("aoc2023-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join("fuzz")).unwrap();
+        fs::write(root.join("src/day01.rs"), "").unwrap();
+        fs::write(root.join("src/day10.rs"), "").unwrap();
+        fs::write(root.join("fuzz/Cargo.toml"), "[package]\n").unwrap();
+
+        let mut new_day = NewDay::new(14);
+        let changes = new_day.create(&root).unwrap();
+        assert!(changes
+            .iter()
+            .all(|(_, change)| *change != Change::Unchanged));
+        assert_eq!(vec![1, 10, 14], days(&root).unwrap());
+
+        let days = fs::read_to_string(root.join(DAYS_PATH)).unwrap();
+        assert!(days.contains("pub mod day14;\n"));
+        assert!(days.contains("        unsolved!(14, B, day14::day14b),\n"));
+        assert!(days.contains("        solver!(1, A, day01::day01a),\n"));
+
+        new_day.sample = Some(String::from("1 2 3\n"));
+        let changes = new_day.create(&root).unwrap();
+        let updated: Vec<_> = changes
+            .iter()
+            .filter(|(_, change)| *change != Change::Unchanged)
+            .map(|(path, _)| path.strip_prefix(&root).unwrap())
+            .collect();
+        assert_eq!(vec![Path::new("data/day14_debug.txt")], updated);
+
+        let manifest = fs::read_to_string(root.join("fuzz/Cargo.toml")).unwrap();
+        assert_eq!(1, manifest.matches("name = \"day14\"").count());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn day_out_of_range() {
+        assert!(NewDay::new(26).create(Path::new(".")).is_err());
+    }
+}
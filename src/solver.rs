@@ -0,0 +1,284 @@
+use crate::cache;
+use crate::error::{catch_panic, AocError};
+use crate::registry::SOLVERS;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Part {
+    A,
+    B,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" => Ok(Part::A),
+            "b" | "B" => Ok(Part::B),
+            _ => Err(AocError::Input(format!(
+                "part should be 'a' or 'b', not {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+/// the days return different number types, this keeps them apart without losing the sign.
+/// answers are equal when their numbers are, `Unsigned(5) == Signed(5)`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Unsigned(usize),
+    Signed(i64),
+}
+
+impl Answer {
+    pub(crate) fn as_i128(self) -> i128 {
+        match self {
+            Answer::Unsigned(number) => number as i128,
+            Answer::Signed(number) => number.into(),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_i128() == other.as_i128()
+    }
+}
+
+impl Eq for Answer {}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Signed(value.into())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(number) => write!(f, "{}", number),
+            Answer::Signed(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+pub trait Solver: Sync {
+    fn day(&self) -> u8;
+    fn part(&self) -> Part;
+    /// name the solver is exported as in the python module, for example `day01a`
+    fn name(&self) -> &'static str;
+    /// false for parts that are not solved yet, those always return [`AocError::NotSolved`]
+    fn solved(&self) -> bool;
+    /// `input` is the content of the puzzle input, not a path to it. every day starts by
+    /// normalizing it with [`crate::input::normalize`]
+    fn solve(&self, input: &str) -> Result<Answer, AocError>;
+    /// changes when the code of the solver changes, the key of the [`cache`]
+    fn version(&self) -> u64;
+}
+
+/// the modules every day uses, a change in one of them is a new version of every solver
+const SHARED_SOURCE: [&str; 5] = [
+    include_str!("error.rs"),
+    include_str!("grid.rs"),
+    include_str!("input.rs"),
+    include_str!("parse.rs"),
+    include_str!("solver.rs"),
+];
+
+pub struct FnSolver {
+    pub(crate) day: u8,
+    pub(crate) part: Part,
+    pub(crate) name: &'static str,
+    pub(crate) solved: bool,
+    pub(crate) func: fn(&str) -> Result<Answer, AocError>,
+    /// the source of the module of the day
+    pub(crate) source: &'static str,
+}
+
+impl Solver for FnSolver {
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn part(&self) -> Part {
+        self.part
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn solved(&self) -> bool {
+        self.solved
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, AocError> {
+        (self.func)(input)
+    }
+
+    fn version(&self) -> u64 {
+        let mut parts = vec![env!("CARGO_PKG_VERSION").as_bytes(), self.source.as_bytes()];
+        parts.extend(SHARED_SOURCE.iter().map(|source| source.as_bytes()));
+        cache::hash(&parts)
+    }
+}
+
+macro_rules! solver {
+    ($day:literal, $part:ident, $module:ident::$func:ident) => {
+        $crate::solver::solver!($day, $part, $module::$func, true)
+    };
+    ($day:literal, $part:ident, $module:ident::$func:ident, $solved:literal) => {
+        &$crate::solver::FnSolver {
+            day: $day,
+            part: $crate::solver::Part::$part,
+            name: stringify!($func),
+            solved: $solved,
+            func: |input| $crate::$module::$func(input).map($crate::solver::Answer::from),
+            source: include_str!(concat!(stringify!($module), ".rs")),
+        }
+    };
+}
+
+/// a part that is not solved yet, its function should return [`AocError::NotSolved`]
+macro_rules! unsolved {
+    ($day:literal, $part:ident, $module:ident::$func:ident) => {
+        $crate::solver::solver!($day, $part, $module::$func, false)
+    };
+}
+
+pub(crate) use {solver, unsolved};
+
+pub fn solvers() -> &'static [&'static dyn Solver] {
+    SOLVERS
+}
+
+pub fn find(day: u8, part: Part) -> Option<&'static dyn Solver> {
+    SOLVERS
+        .iter()
+        .find(|solver| solver.day() == day && solver.part() == part)
+        .copied()
+}
+
+/// the outcome of one solver, with how long it took
+pub struct Run {
+    pub solver: &'static dyn Solver,
+    pub result: Result<Answer, AocError>,
+    pub duration: Duration,
+    /// the answer came from the [`cache`], it is not solved again
+    pub cached: bool,
+}
+
+/// solves `input`, or looks the answer up when the [`cache`] is on
+pub fn run(solver: &'static dyn Solver, input: &str) -> Run {
+    let start = Instant::now();
+    let cache = cache::current();
+
+    if let Some(answer) = cache.as_ref().and_then(|cache| cache.get(solver, input)) {
+        return Run {
+            solver,
+            result: Ok(answer),
+            duration: start.elapsed(),
+            cached: true,
+        };
+    }
+
+    let result = catch_panic(solver.day(), || solver.solve(input));
+    let duration = start.elapsed();
+
+    if let (Some(cache), Ok(answer)) = (&cache, &result) {
+        if let Err(error) = cache.insert(solver, input, *answer) {
+            warn!(name = solver.name(), %error, "could not cache the answer");
+        }
+    }
+
+    Run {
+        solver,
+        result,
+        duration,
+        cached: false,
+    }
+}
+
+/// solves every day that is in `inputs` on the rayon thread pool, the runs are in registry order
+pub fn run_all(inputs: &HashMap<u8, String>) -> Vec<Run> {
+    SOLVERS
+        .par_iter()
+        .filter_map(|solver| inputs.get(&solver.day()).map(|input| run(*solver, input)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_from_str() {
+        assert_eq!(Ok(Part::A), "a".parse());
+        assert_eq!(Ok(Part::B), "B".parse());
+        assert!("c".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn answers_compare_by_value() {
+        assert_eq!(Answer::Unsigned(5), Answer::Signed(5));
+        assert_ne!(Answer::Unsigned(5), Answer::Signed(-5));
+    }
+
+    #[test]
+    fn registry_is_sorted_and_unique() {
+        let keys: Vec<(u8, Part)> = solvers()
+            .iter()
+            .map(|solver| (solver.day(), solver.part()))
+            .collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted, keys);
+    }
+
+    #[test]
+    fn unsolved_parts() {
+        let solver = find(11, Part::A).unwrap();
+        assert!(!solver.solved());
+        assert_eq!(Err(AocError::not_solved(11, Part::A)), solver.solve(""));
+        assert!(find(1, Part::A).unwrap().solved());
+    }
+
+    #[test]
+    fn find_solver() {
+        let solver = find(9, Part::B).unwrap();
+        assert_eq!("day09b", solver.name());
+        assert_eq!(Ok(Answer::Signed(5)), solver.solve("10 13 16 21 30 45"));
+    }
+}
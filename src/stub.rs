@@ -0,0 +1,206 @@
+//! The python type stub `aoc2023/__init__.pyi`.
+//!
+//! The solver functions are added at runtime from the registry, so the stub is generated from
+//! it as well: `aoc2023 stub > aoc2023/__init__.pyi`. A test checks the checked in stub is up to
+//! date.
+
+use crate::benchmark::DEFAULT_RUNS;
+use crate::verify::MANIFEST;
+use crate::{model, solver};
+use std::fmt::Write;
+
+pub const PATH: &str = "aoc2023/__init__.pyi";
+
+const HEADER: &str = r##"# generated by `aoc2023 stub`, do not edit
+import os
+from typing import IO, TYPE_CHECKING, Any, Literal, Optional, TypedDict, Union
+
+if TYPE_CHECKING:
+    # numpy is only needed with the `numpy` feature, it is not a dependency of the package
+    import numpy as np
+
+Input = Union[str, bytes, os.PathLike[str], IO[str], IO[bytes]]
+"""A puzzle input.
+
+A `str` is the puzzle text, a path has to be an `os.PathLike` like `pathlib.Path`. `bytes` is the
+puzzle text as utf-8. Anything with a `.read()` method, like an open file, is read.
+"""
+
+Part = Literal["a", "b"]
+HandTuple = tuple[int, int, int]
+"""`(red, green, blue)`"""
+
+class AocError(ValueError): ...
+
+class ParseError(AocError):
+    day: int
+    line: int
+    column: int
+    text: str
+    expected: str
+
+class InputError(AocError): ...
+
+class UnsolvableError(AocError):
+    day: int
+
+class NotSolvedError(AocError):
+    day: int
+    part: Part
+
+class PanicError(AocError):
+    day: int
+
+class BenchmarkReport(TypedDict):
+    day: int
+    part: Part
+    name: str
+    answer: int
+    runs: int
+    min: float
+    median: float
+    max: float
+    allocations: Optional[int]
+
+class Verification(TypedDict):
+    day: int
+    part: Part
+    input: str
+    expected: int
+    status: Literal["pass", "fail", "unsolved"]
+    message: Optional[str]
+
+class SolverRun(TypedDict):
+    day: int
+    part: Part
+    status: Literal["solved", "unsolved", "error"]
+    answer: Optional[int]
+    error: Optional[str]
+    seconds: float
+    cached: bool
+
+class Explanation(TypedDict):
+    line: int
+    text: str
+    parsed: Any
+    contribution: int
+    details: dict[str, Any]
+
+class CacheEntry(TypedDict):
+    day: int
+    part: Part
+    name: str
+    version: str
+    input: str
+    answer: int
+
+class Tokenizer:
+    @property
+    def tokens(self) -> dict[tuple[int, int], Union[int, str]]:
+        """The numbers and the symbols by `(x, y)`, gears are `"*"` and other symbols `"#"`."""
+    def to_numpy(self) -> np.ndarray:
+        """The schematic as int8 `[y][x]`, the digits, -1 empty, -2 a symbol and -3 a gear.
+
+        Only with the `numpy` feature.
+        """
+"##;
+
+/// the functions of `src/python.rs`, python signature and return type
+const FUNCTIONS: &[(&str, &str)] = &[
+    (
+        "solvers()",
+        "list[tuple[int, Part, str, Literal[\"solved\", \"unsolved\"]]]",
+    ),
+    ("solve(day: int, part: Part, input: Input)", "int"),
+    (
+        "run_all(paths: dict[int, Input])",
+        "dict[str, SolverRun]",
+    ),
+    (
+        "benchmark(day: int, part: Part, runs: int = {runs}, input: Optional[Input] = None)",
+        "BenchmarkReport",
+    ),
+    (
+        "verify(manifest: Union[str, os.PathLike[str]] = \"{manifest}\")",
+        "list[Verification]",
+    ),
+    ("generate(day: int, seed: int, size: int)", "str"),
+    (
+        "explain(day: int, part: Part, input: Input)",
+        "list[Explanation]",
+    ),
+    (
+        "render(day: int, input: Input, format: Literal[\"ansi\", \"svg\"] = \"ansi\")",
+        "str",
+    ),
+    (
+        "cache_enable(directory: Union[str, os.PathLike[str]])",
+        "None",
+    ),
+    ("cache_disable()", "None"),
+    ("cache_entries()", "list[CacheEntry]"),
+    ("cache_clear()", "int"),
+    ("logging_refresh()", "None"),
+    ("day02_parse_hand(input: str)", "HandTuple"),
+    (
+        "day02_parse_game(input: str)",
+        "tuple[int, list[HandTuple]]",
+    ),
+    ("day03a_parse_tokens(schema: str)", "Tokenizer"),
+    ("day07_sort_cards(cards_text: str)", "list[list[int]]"),
+    // only with the `numpy` feature
+    ("to_numpy(day: int, input: Input)", "np.ndarray"),
+    (
+        "day06_ways_to_win(times: np.ndarray, distances: np.ndarray)",
+        "np.ndarray",
+    ),
+    (
+        "day09_extrapolate(histories: np.ndarray, part: Part = \"a\")",
+        "np.ndarray",
+    ),
+];
+
+pub fn python_stub() -> String {
+    let mut stub = String::from(HEADER);
+
+    for (signature, returns) in FUNCTIONS {
+        let signature = signature
+            .replace("{runs}", &DEFAULT_RUNS.to_string())
+            .replace("{manifest}", MANIFEST);
+        writeln!(stub, "\ndef {} -> {}: ...", signature, returns)
+            .expect("writing to a string does not fail");
+    }
+
+    stub.push('\n');
+    for &day in model::DAYS {
+        writeln!(
+            stub,
+            "def day{:02}_parse(input: Input) -> dict[str, Any]: ...",
+            day
+        )
+        .expect("writing to a string does not fail");
+    }
+
+    stub.push('\n');
+    for solver in solver::solvers() {
+        writeln!(stub, "def {}(input: Input) -> int: ...", solver.name())
+            .expect("writing to a string does not fail");
+    }
+
+    stub
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_in_stub_is_up_to_date() {
+        assert_eq!(
+            include_str!("../aoc2023/__init__.pyi"),
+            python_stub(),
+            "run `just stub` to regenerate {}",
+            PATH
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! Checks the solvers against the known answers in `data/answers.json`.
+
+use crate::error::{catch_panic, AocError};
+use crate::input::Input;
+use crate::solver::{self, Answer, Part};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+pub const MANIFEST: &str = "data/answers.json";
+
+/// one line of the manifest, `input` is relative to the repo root
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Expected {
+    pub day: u8,
+    pub part: Part,
+    pub input: PathBuf,
+    pub expected: Answer,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "status", content = "message", rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    /// wrong answer, an error or a panic, the message says which
+    Fail(String),
+    /// the part is not solved yet
+    Unsolved,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail(message) => write!(f, "fail: {}", message),
+            Status::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Verification {
+    #[serde(flatten)]
+    pub entry: Expected,
+    #[serde(flatten)]
+    pub status: Status,
+}
+
+pub fn load(path: &Path) -> Result<Vec<Expected>, AocError> {
+    let text = Input::Path(path.to_path_buf()).read()?;
+    serde_json::from_str(&text)
+        .map_err(|error| AocError::Input(format!("{}: {}", path.display(), error)))
+}
+
+pub fn verify_entry(entry: &Expected) -> Status {
+    let Some(solver) = solver::find(entry.day, entry.part) else {
+        return Status::Unsolved;
+    };
+    let input = match Input::Path(entry.input.clone()).read() {
+        Ok(input) => input,
+        Err(error) => return Status::Fail(error.to_string()),
+    };
+
+    match catch_panic(entry.day, || solver.solve(&input)) {
+        Ok(answer) if answer == entry.expected => Status::Pass,
+        Ok(answer) => Status::Fail(format!("expected {} but found {}", entry.expected, answer)),
+        Err(AocError::NotSolved { .. }) => Status::Unsolved,
+        Err(error) => Status::Fail(error.to_string()),
+    }
+}
+
+/// verifies all entries in parallel, the results are in the order of `entries`
+pub fn verify(entries: &[Expected]) -> Vec<Verification> {
+    entries
+        .par_iter()
+        .map(|entry| Verification {
+            entry: entry.clone(),
+            status: verify_entry(entry),
+        })
+        .collect()
+}
@@ -0,0 +1,55 @@
+import 'dart:io';
+
+import 'package:aoc2023/aoc2023.dart';
+import 'package:test/test.dart';
+
+String debugInput(int day) =>
+    File('data/day${day.toString().padLeft(2, '0')}_debug.txt')
+        .readAsStringSync();
+
+void main() {
+  final library = Aoc2023.defaultLibraryPath();
+  final skip = File(library).existsSync()
+      ? null
+      : 'run `cargo build --release` first, $library is missing';
+  late Aoc2023 aoc;
+
+  setUpAll(() {
+    if (skip == null) {
+      aoc = Aoc2023.open();
+    }
+  });
+
+  test('day02a', () {
+    expect(aoc.solve(2, 'a', debugInput(2)), 8);
+  }, skip: skip);
+
+  test('day09b', () {
+    expect(aoc.solve(9, 'b', debugInput(9)), 2);
+  }, skip: skip);
+
+  test('day05_parse', () {
+    final puzzle = aoc.parse(5, debugInput(5)) as Map<String, dynamic>;
+    expect(puzzle['seeds'], [79, 14, 55, 13]);
+  }, skip: skip);
+
+  test('solvers', () {
+    final solvers = aoc.solvers();
+    expect(solvers.first.name, 'day01a');
+    expect(solvers.where((solver) => solver.day == 12).length, 2);
+  }, skip: skip);
+
+  test('parse error', () {
+    expect(
+        () => aoc.solve(8, 'a', 'LR\n\nAAA = (BBB CCC)'),
+        throwsA(isA<AocException>()
+            .having((error) => error.status, 'status', AocStatus.parseError)));
+  }, skip: skip);
+
+  test('not solved', () {
+    expect(
+        () => aoc.solve(11, 'a', ''),
+        throwsA(isA<AocException>()
+            .having((error) => error.status, 'status', AocStatus.notSolved)));
+  }, skip: skip);
+}
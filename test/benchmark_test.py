@@ -0,0 +1,27 @@
+import pathlib
+
+import aoc2023
+import pytest
+
+
+def test_benchmark():
+    report = aoc2023.benchmark(1, "a", runs=3)
+
+    assert 1 == report["day"]
+    assert "a" == report["part"]
+    assert 55130 == report["answer"]
+    assert 3 == report["runs"]
+    assert report["min"] <= report["median"] <= report["max"]
+    # the python module keeps the allocator of the interpreter, so nothing is counted
+    assert report["allocations"] is None
+
+
+def test_benchmark_input():
+    report = aoc2023.benchmark(9, "b", runs=1, input=pathlib.Path("data/day09.txt"))
+
+    assert 1140 == report["answer"]
+
+
+def test_benchmark_no_runs():
+    with pytest.raises(aoc2023.InputError):
+        aoc2023.benchmark(1, "a", runs=0)
@@ -0,0 +1,40 @@
+import aoc2023
+import pytest
+
+INPUT = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n"
+
+
+@pytest.fixture
+def cache(tmp_path):
+    aoc2023.cache_enable(tmp_path)
+    yield tmp_path
+    aoc2023.cache_disable()
+
+
+def test_solve_is_cached(cache):
+    assert 114 == aoc2023.day09a(INPUT)
+    assert 114 == aoc2023.solve(9, "a", INPUT)
+
+    [entry] = aoc2023.cache_entries()
+    assert "day09a" == entry["name"]
+    assert "a" == entry["part"]
+    assert 114 == entry["answer"]
+
+
+def test_errors_are_not_cached(cache):
+    with pytest.raises(aoc2023.ParseError):
+        aoc2023.day09a("1 x 3\n")
+
+    assert [] == aoc2023.cache_entries()
+
+
+def test_clear(cache):
+    aoc2023.run_all({9: INPUT})
+
+    assert 2 == aoc2023.cache_clear()
+    assert [] == aoc2023.cache_entries()
+
+
+def test_disabled():
+    with pytest.raises(aoc2023.InputError):
+        aoc2023.cache_entries()
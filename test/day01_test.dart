@@ -0,0 +1,16 @@
+import 'dart:io';
+
+import 'package:aoc2023/day01.dart';
+import 'package:test/test.dart';
+
+void main() {
+  final input = File('data/day01.txt').readAsStringSync();
+
+  test('day01a', () {
+    expect(day01a(input), 55130);
+  });
+
+  test('day01b', () {
+    expect(day01b(input), 54985);
+  });
+}
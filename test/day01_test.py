@@ -1,9 +1,11 @@
+import pathlib
+
 import aoc2023
 
 
 def test_day01a():
-    assert 55130 == aoc2023.day01a("data/day01.txt")
+    assert 55130 == aoc2023.day01a(pathlib.Path("data/day01.txt"))
 
 
 def test_day01b():
-    assert 54985 == aoc2023.day01b("data/day01.txt")
+    assert 54985 == aoc2023.day01b(pathlib.Path("data/day01.txt"))
@@ -1,3 +1,5 @@
+import pathlib
+
 import aoc2023
 
 
@@ -12,8 +14,8 @@ def test_parse_game():
 
 
 def test_day02a():
-    assert 1734 == aoc2023.day02a("data/day02.txt")
+    assert 1734 == aoc2023.day02a(pathlib.Path("data/day02.txt"))
 
 
 def test_day02b():
-    assert 70387 == aoc2023.day02b("data/day02.txt")
+    assert 70387 == aoc2023.day02b(pathlib.Path("data/day02.txt"))
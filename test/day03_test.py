@@ -1,3 +1,5 @@
+import pathlib
+
 import aoc2023
 
 
@@ -18,7 +20,6 @@ def test_day03a_parse_tokens1():
 
 
 def test_day03a_parse_tokens2():
-    # schema = "467..114..\r\n...*......\r\n..35..633.\r\n......#...\r\n617*......\r\n.....+.58.\r\n..592.....\r\n......755.\r\n...$.*....\r\n.664.598.."
     schema = """467..114..
 ...*......
 ..35..633.
@@ -70,9 +71,16 @@ def test_day03a_parse_tokens2():
     } == schematic.tokens
 
 
+def test_day03a_parse_tokens_crlf():
+    schema = "467..114..\r\n...*......\r\n..35..633.\r\n"
+
+    schematic = aoc2023.day03a_parse_tokens(schema)
+    assert schematic.tokens == aoc2023.day03a_parse_tokens(schema.replace("\r", "")).tokens
+
+
 def test_day03a():
-    assert 540131 == aoc2023.day03a("data/day03.txt")
+    assert 540131 == aoc2023.day03a(pathlib.Path("data/day03.txt"))
 
 
 def test_day03b():
-    assert 86879020 == aoc2023.day03b("data/day03.txt")
+    assert 86879020 == aoc2023.day03b(pathlib.Path("data/day03.txt"))
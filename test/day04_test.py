@@ -1,9 +1,11 @@
+import pathlib
+
 import aoc2023
 
 
 def test_day04a():
-    assert 26914 == aoc2023.day04a("data/day04.txt")
+    assert 26914 == aoc2023.day04a(pathlib.Path("data/day04.txt"))
 
 
 def test_day04b():
-    assert 13080971 == aoc2023.day04b("data/day04.txt")
+    assert 13080971 == aoc2023.day04b(pathlib.Path("data/day04.txt"))
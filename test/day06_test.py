@@ -1,9 +1,11 @@
+import pathlib
+
 import aoc2023
 
 
 def test_day06a():
-    assert 5133600 == aoc2023.day06a("data/day06.txt")
+    assert 5133600 == aoc2023.day06a(pathlib.Path("data/day06.txt"))
 
 
 def test_day06b():
-    assert 40651271 == aoc2023.day06b("data/day06.txt")
+    assert 40651271 == aoc2023.day06b(pathlib.Path("data/day06.txt"))
@@ -1,3 +1,5 @@
+import pathlib
+
 import aoc2023
 import pytest
 
@@ -39,9 +41,9 @@ QQQJA 483"""
 
 
 def test_day07a():
-    assert 250120186 == aoc2023.day07a("data/day07.txt")
+    assert 250120186 == aoc2023.day07a(pathlib.Path("data/day07.txt"))
 
 
 @pytest.mark.skip(reason="that poker rules took too long")
 def test_day07b():
-    assert 0 == aoc2023.day07b("data/day07_debug.txt")
+    assert 0 == aoc2023.day07b(pathlib.Path("data/day07_debug.txt"))
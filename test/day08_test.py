@@ -1,11 +1,15 @@
+import pathlib
+
 import aoc2023
-import pytest
 
 
 def test_day08a():
-    assert 14257 == aoc2023.day08a("data/day08.txt")
+    assert 14257 == aoc2023.day08a(pathlib.Path("data/day08.txt"))
+
+
+def test_day08b_debug():
+    assert 6 == aoc2023.day08b(pathlib.Path("data/day08_debug.txt"))
 
 
-@pytest.mark.skip(reason="brute force takes too long")
 def test_day08b():
-    assert 0 == aoc2023.day08b("data/day08.txt")
+    assert 16187743689077 == aoc2023.day08b(pathlib.Path("data/day08.txt"))
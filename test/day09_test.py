@@ -1,9 +1,11 @@
+import pathlib
+
 import aoc2023
 
 
 def test_day09a():
-    assert 1819125966 == aoc2023.day09a("data/day09.txt")
+    assert 1819125966 == aoc2023.day09a(pathlib.Path("data/day09.txt"))
 
 
 def test_day09b():
-    assert 1140 == aoc2023.day09b("data/day09.txt")
+    assert 1140 == aoc2023.day09b(pathlib.Path("data/day09.txt"))
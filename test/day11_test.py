@@ -1,12 +1,14 @@
+import pathlib
+
 import aoc2023
 import pytest
 
 
 @pytest.mark.skip(reason="not implemented")
 def test_day11a():
-    assert 0 == aoc2023.day11a("data/day11_debug.txt")
+    assert 0 == aoc2023.day11a(pathlib.Path("data/day11_debug.txt"))
 
 
 @pytest.mark.skip(reason="not implemented")
 def test_day11b():
-    assert 0 == aoc2023.day11b("data/day11_debug.txt")
+    assert 0 == aoc2023.day11b(pathlib.Path("data/day11_debug.txt"))
@@ -1,9 +1,14 @@
+import pathlib
+
 import aoc2023
+import pytest
 
 
+@pytest.mark.skip(reason="not implemented")
 def test_day12a():
-    assert 0 == aoc2023.day12a("data/day12_debug.txt")
+    assert 0 == aoc2023.day12a(pathlib.Path("data/day12_debug.txt"))
 
 
+@pytest.mark.skip(reason="not implemented")
 def test_day12b():
-    assert 0 == aoc2023.day12b("data/day12_debug.txt")
+    assert 0 == aoc2023.day12b(pathlib.Path("data/day12_debug.txt"))
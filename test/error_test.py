@@ -0,0 +1,24 @@
+import pathlib
+
+import aoc2023
+import pytest
+
+
+def test_parse_error_position():
+    with pytest.raises(aoc2023.ParseError) as error:
+        aoc2023.day02_parse_game("Game 4: 1 green, 3 rad; 3 green")
+
+    assert 2 == error.value.day
+    assert 1 == error.value.line
+    assert 20 == error.value.column
+    assert "rad" == error.value.text
+
+
+def test_errors_are_value_errors():
+    with pytest.raises(ValueError):
+        aoc2023.day02_parse_hand("1 green 2 red")
+
+
+def test_input_error():
+    with pytest.raises(aoc2023.InputError):
+        aoc2023.day01a(pathlib.Path("data/does_not_exist.txt"))
@@ -0,0 +1,33 @@
+import pathlib
+
+import aoc2023
+import pytest
+
+
+def test_contributions_add_up():
+    explanations = aoc2023.explain(1, "b", pathlib.Path("data/day01_debug.txt"))
+
+    assert 281 == sum(line["contribution"] for line in explanations)
+    assert {"line": 1, "text": "two1nine", "parsed": [2, 1, 9]} == {
+        key: explanations[0][key] for key in ["line", "text", "parsed"]
+    }
+    assert {"first": 2, "last": 9} == explanations[0]["details"]
+
+
+def test_day04b_copies():
+    explanations = aoc2023.explain(4, "b", pathlib.Path("data/day04_debug.txt"))
+
+    assert [1, 2, 4, 8, 14, 1] == [line["details"]["copies"] for line in explanations]
+
+
+def test_parse_error_line():
+    with pytest.raises(aoc2023.ParseError, match="line 2"):
+        aoc2023.explain(9, "a", "1 2 3\n1 x 3\n")
+
+
+def test_not_explained():
+    with pytest.raises(aoc2023.InputError):
+        aoc2023.explain(5, "a", pathlib.Path("data/day05_debug.txt"))
+
+    with pytest.raises(ValueError):
+        aoc2023.explain(1, "c", pathlib.Path("data/day01_debug.txt"))
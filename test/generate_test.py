@@ -0,0 +1,23 @@
+import aoc2023
+import pytest
+
+
+@pytest.mark.parametrize("day", range(1, 13))
+def test_generated_input_parses(day):
+    input = aoc2023.generate(day, 1, 8)
+
+    assert input == aoc2023.generate(day, 1, 8)
+    assert isinstance(getattr(aoc2023, f"day{day:02}_parse")(input), dict)
+
+
+def test_day04_size():
+    assert 25 == len(aoc2023.generate(4, 0, 25).splitlines())
+
+
+def test_day10_loop():
+    assert aoc2023.day10a(aoc2023.generate(10, 3, 20)) > 0
+
+
+def test_unknown_day():
+    with pytest.raises(aoc2023.InputError):
+        aoc2023.generate(13, 0, 10)
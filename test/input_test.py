@@ -0,0 +1,58 @@
+import io
+import pathlib
+
+import aoc2023
+import pytest
+
+
+def read_day01():
+    with open("data/day01.txt") as f:
+        return f.read()
+
+
+def test_string_without_newline_is_text():
+    assert 18 == aoc2023.day09a("0 3 6 9 12 15")
+
+
+def test_text():
+    assert 55130 == aoc2023.day01a(read_day01())
+
+
+def test_bytes():
+    assert 55130 == aoc2023.day01a(read_day01().encode())
+
+
+def test_pathlib_path():
+    assert 55130 == aoc2023.day01a(pathlib.Path("data/day01.txt"))
+
+
+def test_file_like():
+    assert 55130 == aoc2023.day01a(io.StringIO(read_day01()))
+
+
+def test_open_file():
+    with open("data/day01.txt", "rb") as f:
+        assert 55130 == aoc2023.day01a(f)
+
+
+def test_solve_with_text():
+    assert 54985 == aoc2023.solve(1, "b", read_day01())
+
+
+def test_invalid_utf8():
+    with pytest.raises(aoc2023.InputError):
+        aoc2023.day01a(b"\xff\xfe\n")
+
+
+def test_wrong_type():
+    with pytest.raises(TypeError):
+        aoc2023.day01a(42)
+
+
+@pytest.mark.parametrize("day, part", [(1, "b"), (2, "a"), (3, "b"), (5, "a"), (8, "b"), (10, "a")])
+def test_windows_line_endings(day, part):
+    with open(f"data/day{day:02}_debug.txt") as f:
+        sample = f.read()
+    windows = "\ufeff" + sample.replace("\n", "\r\n") + "\r\n"
+
+    assert aoc2023.solve(day, part, sample) == aoc2023.solve(day, part, windows)
@@ -0,0 +1,91 @@
+import logging
+import pathlib
+
+import aoc2023
+
+
+class Collect(logging.Handler):
+    def __init__(self):
+        super().__init__()
+        self.records = []
+
+    def emit(self, record):
+        self.records.append(record)
+
+
+def collect(name, level):
+    logger = logging.getLogger(name)
+    handler = Collect()
+    logger.addHandler(handler)
+    logger.setLevel(level)
+    aoc2023.logging_refresh()
+    return logger, handler
+
+
+def reset(logger, handler):
+    logger.removeHandler(handler)
+    logger.setLevel(logging.NOTSET)
+    aoc2023.logging_refresh()
+
+
+def test_day05_debug_goes_to_its_logger():
+    logger, handler = collect("aoc2023.day05", logging.DEBUG)
+    try:
+        aoc2023.day05b(pathlib.Path("data/day05_debug.txt"))
+    finally:
+        reset(logger, handler)
+
+    assert handler.records
+    assert all("aoc2023.day05" == record.name for record in handler.records)
+    assert any("location range" in record.getMessage() for record in handler.records)
+
+
+def test_trace_level():
+    logger, handler = collect("aoc2023.day12", 5)
+    try:
+        try:
+            aoc2023.day12a(pathlib.Path("data/day12_debug.txt"))
+        except aoc2023.NotSolvedError:
+            pass
+    finally:
+        reset(logger, handler)
+
+    assert "TRACE" == handler.records[0].levelname
+    assert handler.records[0].getMessage().startswith("trimmed row row=Row")
+
+
+def test_silent_by_default():
+    handler = Collect()
+    logging.getLogger("aoc2023").addHandler(handler)
+    try:
+        aoc2023.day05b(pathlib.Path("data/day05_debug.txt"))
+    finally:
+        logging.getLogger("aoc2023").removeHandler(handler)
+
+    assert [] == handler.records
+
+
+def test_level_is_read_on_refresh():
+    handler = Collect()
+    logger = logging.getLogger("aoc2023.day05")
+    logger.addHandler(handler)
+    logger.setLevel(logging.DEBUG)
+    try:
+        aoc2023.day05b(pathlib.Path("data/day05_debug.txt"))
+        assert [] == handler.records
+
+        aoc2023.logging_refresh()
+        aoc2023.day05b(pathlib.Path("data/day05_debug.txt"))
+        assert handler.records
+    finally:
+        reset(logger, handler)
+
+
+def test_new_logger_has_the_level_of_its_parent():
+    logger, handler = collect("aoc2023", logging.DEBUG)
+    try:
+        aoc2023.day05b(pathlib.Path("data/day05_debug.txt"))
+    finally:
+        reset(logger, handler)
+
+    assert any("aoc2023.day05" == record.name for record in handler.records)
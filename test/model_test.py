@@ -0,0 +1,37 @@
+import pathlib
+
+import aoc2023
+import pytest
+
+
+@pytest.mark.parametrize("day", range(1, 13))
+def test_parse_samples(day):
+    assert isinstance(getattr(aoc2023, f"day{day:02}_parse")(pathlib.Path(f"data/day{day:02}_debug.txt")), dict)
+
+
+def test_day02_parse():
+    assert {
+        "games": [{"id": 1, "hands": [{"red": 4, "green": 0, "blue": 3}]}]
+    } == aoc2023.day02_parse("Game 1: 3 blue, 4 red\n")
+
+
+def test_day05_parse():
+    almanac = aoc2023.day05_parse(pathlib.Path("data/day05_debug.txt"))
+
+    assert [79, 14, 55, 13] == almanac["seeds"]
+    assert "soil" == almanac["maps"]["seed"]["to"]
+
+
+def test_day10_parse():
+    field = aoc2023.day10_parse(".....\n.S-7.\n.|.|.\n.L-J.\n.....\n")
+
+    assert [1, 1] == field["start_position"]
+    assert "BendSouthWest" == field["grid"][1][3]
+    assert field["grid"][0][0] is None
+
+
+def test_parse_error():
+    with pytest.raises(aoc2023.ParseError) as error:
+        aoc2023.day04_parse("Card 1: 41 48\n")
+
+    assert 1 == error.value.line
@@ -0,0 +1,33 @@
+import pathlib
+
+import aoc2023
+import pytest
+
+
+def test_not_solved_error():
+    with pytest.raises(aoc2023.NotSolvedError) as error:
+        aoc2023.day11a(pathlib.Path("data/day11_debug.txt"))
+
+    assert 11 == error.value.day
+    assert "a" == error.value.part
+
+
+def test_not_solved_is_aoc_error():
+    with pytest.raises(aoc2023.AocError):
+        aoc2023.solve(12, "b", pathlib.Path("data/day12_debug.txt"))
+
+
+def test_solvers_status():
+    statuses = {name: status for _, _, name, status in aoc2023.solvers()}
+
+    assert "solved" == statuses["day01a"]
+    assert "unsolved" == statuses["day07b"]
+    assert "unsolved" == statuses["day11a"]
+
+
+def test_unknown_pipe_is_parse_error():
+    with pytest.raises(aoc2023.ParseError) as error:
+        aoc2023.day10a("S-X\n")
+
+    assert 10 == error.value.day
+    assert 3 == error.value.column
@@ -0,0 +1,69 @@
+import pathlib
+
+import aoc2023
+import pytest
+
+np = pytest.importorskip("numpy")
+
+pytestmark = pytest.mark.skipif(
+    not hasattr(aoc2023, "to_numpy"), reason="built without the numpy feature"
+)
+
+
+def test_day03_tokenizer():
+    tokenizer = aoc2023.day03a_parse_tokens("467.\n..*.\n.#5.\n")
+    expected = np.array([[4, 6, 7, -1], [-1, -1, -3, -1], [-1, -2, 5, -1]], dtype=np.int8)
+
+    np.testing.assert_array_equal(expected, tokenizer.to_numpy())
+    np.testing.assert_array_equal(expected, aoc2023.to_numpy(3, "467.\n..*.\n.#5.\n"))
+
+
+def test_day10_tiles():
+    tiles = aoc2023.to_numpy(10, pathlib.Path("data/day10_debug.txt"))
+
+    assert np.uint8 == tiles.dtype
+    assert ord("S") in tiles
+
+
+def test_day11_galaxies():
+    galaxies = aoc2023.to_numpy(11, "#..\n...\n..#\n.#.\n")
+
+    assert np.bool_ == galaxies.dtype
+    assert (4, 3) == galaxies.shape
+    assert [(0, 0), (2, 2), (3, 1)] == list(zip(*np.nonzero(galaxies)))
+
+
+def test_day12_springs():
+    springs = aoc2023.to_numpy(12, "#.? 1,1\n# 1\n")
+
+    np.testing.assert_array_equal(np.array([[1, 0, 2], [1, -1, -1]]), springs)
+
+
+def test_day06_ways_to_win():
+    races = aoc2023.to_numpy(6, pathlib.Path("data/day06_debug.txt"))
+    ways = aoc2023.day06_ways_to_win(races[:, 0], races[:, 1])
+
+    np.testing.assert_array_equal(np.array([4, 8, 9]), ways)
+    assert aoc2023.day06a(pathlib.Path("data/day06_debug.txt")) == ways.prod()
+
+
+def test_day09_extrapolate():
+    histories = aoc2023.to_numpy(9, pathlib.Path("data/day09_debug.txt"))
+
+    np.testing.assert_array_equal(
+        np.array([18, 28, 68]), aoc2023.day09_extrapolate(histories)
+    )
+    np.testing.assert_array_equal(
+        np.array([-3, 0, 5]), aoc2023.day09_extrapolate(histories, "b")
+    )
+
+
+def test_errors():
+    with pytest.raises(aoc2023.InputError):
+        aoc2023.to_numpy(1, "1abc2\n")
+
+    with pytest.raises(aoc2023.InputError):
+        aoc2023.to_numpy(9, "1 2 3\n1 2\n")
+
+    with pytest.raises(aoc2023.InputError):
+        aoc2023.day06_ways_to_win(np.array([7]), np.array([], dtype=np.int64))
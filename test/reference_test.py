@@ -0,0 +1,33 @@
+import subprocess
+import sys
+
+from reference import day01
+
+
+def test_day01a():
+    with open("data/day01.txt") as f:
+        assert 55130 == day01.part_a(f.read())
+
+
+def test_day01b():
+    with open("data/day01.txt") as f:
+        assert 54985 == day01.part_b(f.read())
+
+
+def run(day, part, text):
+    return subprocess.run(
+        [sys.executable, "-m", "reference", str(day), part],
+        input=text,
+        capture_output=True,
+        text=True,
+    )
+
+
+def test_runner_answer():
+    result = run(1, "b", "two1nine\neightwothree\n")
+    assert 0 == result.returncode
+    assert "112\n" == result.stdout
+
+
+def test_runner_not_implemented():
+    assert 3 == run(12, "a", "").returncode
@@ -0,0 +1,33 @@
+import pathlib
+
+import aoc2023
+import pytest
+
+SCHEMA = "467..114\n...*....\n..35.&..\n"
+
+
+def test_ansi():
+    picture = aoc2023.render(3, SCHEMA)
+
+    assert "\x1b[1;32m467\x1b[0m" in picture
+    assert "\x1b[31m114\x1b[0m" in picture
+    assert "a gear, it touches exactly two part numbers" in picture
+
+
+def test_svg():
+    picture = aoc2023.render(10, pathlib.Path("data/day10_debug.txt"), "svg")
+
+    assert picture.startswith("<svg ")
+    assert picture.endswith("</svg>\n")
+    assert "<title>day 10</title>" in picture
+
+
+def test_errors():
+    with pytest.raises(aoc2023.InputError):
+        aoc2023.render(5, pathlib.Path("data/day05_debug.txt"))
+
+    with pytest.raises(aoc2023.InputError):
+        aoc2023.render(3, SCHEMA, "png")
+
+    with pytest.raises(aoc2023.ParseError):
+        aoc2023.render(10, ".S-7\n.|x|\n")
@@ -0,0 +1,42 @@
+import pathlib
+from concurrent.futures import ThreadPoolExecutor
+
+import aoc2023
+
+
+def test_run_all():
+    results = aoc2023.run_all({1: pathlib.Path("data/day01.txt"), 9: pathlib.Path("data/day09.txt")})
+
+    assert {"day01a", "day01b", "day09a", "day09b"} == set(results)
+    assert 55130 == results["day01a"]["answer"]
+    assert 1140 == results["day09b"]["answer"]
+    assert all("solved" == result["status"] for result in results.values())
+    assert all(result["seconds"] >= 0.0 for result in results.values())
+
+
+def test_run_all_mixes_solved_and_unsolved():
+    results = aoc2023.run_all({7: pathlib.Path("data/day07.txt"), 1: pathlib.Path("data/day01.txt")})
+
+    assert "solved" == results["day07a"]["status"]
+    assert "unsolved" == results["day07b"]["status"]
+    assert results["day07b"]["answer"] is None
+    assert 55130 == results["day01a"]["answer"]
+
+
+def test_run_all_keeps_errors_per_solver():
+    results = aoc2023.run_all({9: "1 x 3\n", 1: pathlib.Path("data/day01.txt")})
+
+    assert "error" == results["day09a"]["status"]
+    assert "line 1" in results["day09a"]["error"]
+    assert "solved" == results["day01b"]["status"]
+
+
+def test_run_all_empty():
+    assert {} == aoc2023.run_all({})
+
+
+def test_solvers_from_threads():
+    with ThreadPoolExecutor(max_workers=4) as executor:
+        answers = list(executor.map(aoc2023.day01a, [pathlib.Path("data/day01.txt")] * 4))
+
+    assert [55130] * 4 == answers
@@ -0,0 +1,40 @@
+import ast
+import inspect
+import pathlib
+
+import aoc2023
+
+# only in a build with the `numpy` feature
+NUMPY_FUNCTIONS = {"to_numpy", "day06_ways_to_win", "day09_extrapolate"}
+
+
+def stub_functions():
+    stub = pathlib.Path(aoc2023.__file__).with_suffix(".pyi")
+    tree = ast.parse(stub.read_text())
+    return {
+        node.name: [arg.arg for arg in node.args.args]
+        for node in tree.body
+        if isinstance(node, ast.FunctionDef)
+    }
+
+
+def test_stub_covers_every_function():
+    exported = {
+        name
+        for name, value in vars(aoc2023).items()
+        if callable(value) and not name.startswith("_") and not isinstance(value, type)
+    }
+
+    assert exported == set(stub_functions()) - (NUMPY_FUNCTIONS - exported)
+
+
+def test_stub_matches_signatures():
+    for name, parameters in stub_functions().items():
+        if name in NUMPY_FUNCTIONS and not hasattr(aoc2023, name):
+            continue
+        signature = inspect.signature(getattr(aoc2023, name))
+        assert parameters == list(signature.parameters), name
+
+
+def test_py_typed_is_shipped():
+    assert pathlib.Path(aoc2023.__file__).with_name("py.typed").exists()
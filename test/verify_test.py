@@ -0,0 +1,36 @@
+import json
+
+import aoc2023
+
+
+def statuses():
+    return {
+        (result["day"], result["part"], result["input"]): result["status"]
+        for result in aoc2023.verify()
+    }
+
+
+def test_verify_covers_manifest():
+    with open("data/answers.json") as f:
+        manifest = json.load(f)
+
+    assert len(manifest) == len(aoc2023.verify())
+
+
+def test_verify_pass():
+    assert "pass" == statuses()[(1, "a", "data/day01.txt")]
+
+
+def test_verify_unsolved():
+    assert "unsolved" == statuses()[(12, "a", "data/day12_debug.txt")]
+
+
+def test_verify_fail(tmp_path):
+    manifest = tmp_path / "answers.json"
+    manifest.write_text(
+        '[{"day": 1, "part": "a", "input": "data/day01.txt", "expected": 1}]'
+    )
+
+    [result] = aoc2023.verify(str(manifest))
+    assert "fail" == result["status"]
+    assert "expected 1 but found 55130" == result["message"]
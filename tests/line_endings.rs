@@ -0,0 +1,124 @@
+//! Every sample in `data/dayNN_debug.txt` with windows line endings, a BOM or extra whitespace
+//! should parse and solve the same as the original.
+
+use aoc2023::{
+    day01, day02, day03, day04, day06, day07, day09, day10, day11, day12, model, solver,
+};
+use std::fs;
+
+fn sample(day: u8) -> String {
+    fs::read_to_string(format!("data/day{:02}_debug.txt", day)).unwrap()
+}
+
+/// the name of the variant and the sample changed like that
+fn variants(sample: &str) -> Vec<(&'static str, String)> {
+    let crlf = sample.replace("\r\n", "\n").replace('\n', "\r\n");
+    vec![
+        ("crlf", crlf.clone()),
+        ("crlf without a final newline", crlf.trim_end().to_string()),
+        ("bom", format!("\u{feff}{}", sample)),
+        ("bom and crlf", format!("\u{feff}{}", crlf)),
+        ("trailing blank lines", format!("{}\n\n  \n", sample)),
+        ("crlf and trailing blank lines", format!("{}\r\n\r\n", crlf)),
+        (
+            "trailing whitespace",
+            sample
+                .lines()
+                .map(|line| format!("{} \t", line))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+    ]
+}
+
+#[test]
+fn solvers() {
+    for solver in solver::solvers() {
+        let sample = sample(solver.day());
+        let expected = solver.solve(&sample);
+
+        for (name, variant) in variants(&sample) {
+            assert_eq!(
+                expected,
+                solver.solve(&variant),
+                "{} with {}",
+                solver.name(),
+                name
+            );
+        }
+    }
+}
+
+#[test]
+fn parsers() {
+    for &day in model::DAYS {
+        let sample = sample(day);
+        let expected = model::parse(day, &sample);
+
+        for (name, variant) in variants(&sample) {
+            assert_eq!(
+                expected,
+                model::parse(day, &variant),
+                "day {:02} with {}",
+                day,
+                name
+            );
+        }
+    }
+}
+
+/// `expected` for the sample of `day` and every variant of it, `f` is called directly
+fn check_direct<T: PartialEq + std::fmt::Debug>(day: u8, what: &str, f: impl Fn(&str) -> T) {
+    let sample = sample(day);
+    let expected = f(&sample);
+
+    for (name, variant) in variants(&sample) {
+        assert_eq!(expected, f(&variant), "{} with {}", what, name);
+    }
+}
+
+#[test]
+fn explanations() {
+    check_direct(1, "day01a_explain", day01::day01a_explain);
+    check_direct(1, "day01b_explain", day01::day01b_explain);
+    check_direct(2, "day02a_explain", day02::day02a_explain);
+    check_direct(2, "day02b_explain", day02::day02b_explain);
+    check_direct(4, "day04a_explain", day04::day04a_explain);
+    check_direct(4, "day04b_explain", day04::day04b_explain);
+    check_direct(7, "day07a_explain", day07::day07a_explain);
+    check_direct(9, "day09a_explain", day09::day09a_explain);
+    check_direct(9, "day09b_explain", day09::day09b_explain);
+}
+
+#[test]
+fn pictures() {
+    check_direct(3, "day03_render", day03::day03_render);
+    check_direct(10, "day10_render", day10::day10_render);
+    check_direct(11, "day11_render", day11::day11_render);
+}
+
+#[test]
+fn arrays() {
+    check_direct(3, "day03a_parse_tokens", |input| {
+        day03::day03a_parse_tokens(input).map(|tokenizer| tokenizer.codes())
+    });
+    check_direct(6, "day06_races", day06::day06_races);
+    check_direct(9, "day09_histories", day09::day09_histories);
+    check_direct(10, "day10_tiles", day10::day10_tiles);
+    check_direct(11, "day11_galaxies", day11::day11_galaxies);
+    check_direct(12, "day12_springs", day12::day12_springs);
+}
+
+#[test]
+fn small_parsers() {
+    let hand = |input: &str| day02::day02_parse_hand(input);
+    assert_eq!(hand("3 blue, 4 red"), hand("\u{feff}3 blue, 4 red\r\n"));
+
+    let game = |input: &str| day02::day02_parse_game(input);
+    assert_eq!(
+        game("Game 1: 3 blue; 4 red"),
+        game("\u{feff}Game 1: 3 blue; 4 red \r\n")
+    );
+
+    check_direct(7, "day07_sort_cards", day07::day07_sort_cards);
+}
@@ -0,0 +1,50 @@
+//! The inputs the fuzz targets crashed on, these are in `fuzz/regressions/dayNN/`.
+//!
+//! Like the fuzz targets every input is parsed and solved, errors are fine but panics are not.
+
+use aoc2023::error::{catch_panic, AocError};
+use aoc2023::{model, solver};
+use std::fs;
+use std::path::Path;
+
+fn check(day: u8) {
+    let directory =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("fuzz/regressions/day{:02}", day));
+    let Ok(entries) = fs::read_dir(&directory) else {
+        return;
+    };
+
+    for entry in entries {
+        let path = entry.unwrap().path();
+        let input = fs::read_to_string(&path).unwrap();
+
+        let parsed = catch_panic(day, || model::parse(day, &input));
+        assert!(
+            !matches!(parsed, Err(AocError::Panicked { .. })),
+            "parsing {} panicked: {:?}",
+            path.display(),
+            parsed
+        );
+
+        for solver in solver::solvers()
+            .iter()
+            .filter(|solver| solver.day() == day)
+        {
+            let result = catch_panic(day, || solver.solve(&input));
+            assert!(
+                !matches!(result, Err(AocError::Panicked { .. })),
+                "{} of {} panicked: {:?}",
+                solver.name(),
+                path.display(),
+                result
+            );
+        }
+    }
+}
+
+#[test]
+fn every_day() {
+    for &day in model::DAYS {
+        check(day);
+    }
+}
@@ -0,0 +1,64 @@
+//! The examples of the puzzle texts, these are in `data/dayNN_debug.txt`
+
+use aoc2023::*;
+
+macro_rules! sample {
+    ($(#[$attribute:meta])* $name:ident, $module:ident, $file:literal, $expected:expr) => {
+        #[test]
+        $(#[$attribute])*
+        fn $name() {
+            let input = include_str!(concat!("../data/", $file));
+            assert_eq!(Ok($expected), $module::$name(input));
+        }
+    };
+}
+
+sample!(day01b, day01, "day01_debug.txt", 281);
+sample!(day02a, day02, "day02_debug.txt", 8);
+sample!(day02b, day02, "day02_debug.txt", 2286);
+sample!(day03a, day03, "day03_debug.txt", 4361);
+sample!(day03b, day03, "day03_debug.txt", 467835);
+sample!(day04a, day04, "day04_debug.txt", 13);
+sample!(day04b, day04, "day04_debug.txt", 30);
+sample!(day05a, day05, "day05_debug.txt", 35);
+sample!(day05b, day05, "day05_debug.txt", 46);
+sample!(day06a, day06, "day06_debug.txt", 288);
+sample!(day06b, day06, "day06_debug.txt", 71503);
+sample!(day07a, day07, "day07_debug.txt", 6440);
+sample!(
+    #[ignore = "that poker rules took too long"]
+    day07b,
+    day07,
+    "day07_debug.txt",
+    5905
+);
+sample!(day08b, day08, "day08_debug.txt", 6);
+sample!(day09a, day09, "day09_debug.txt", 114);
+sample!(day09b, day09, "day09_debug.txt", 2);
+sample!(day10a, day10, "day10_debug.txt", 8);
+sample!(
+    #[ignore = "not implemented"]
+    day12a,
+    day12,
+    "day12_debug.txt",
+    4
+);
+sample!(
+    #[ignore = "not implemented"]
+    day12b,
+    day12,
+    "day12_debug.txt",
+    16384
+);
+
+#[test]
+fn day01a() {
+    let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+    assert_eq!(Ok(142), day01::day01a(input));
+}
+
+#[test]
+fn day08a() {
+    let input = "RL\n\nAAA = (BBB, CCC)\nBBB = (DDD, EEE)\nCCC = (ZZZ, GGG)\nDDD = (DDD, DDD)\nEEE = (EEE, EEE)\nGGG = (GGG, GGG)\nZZZ = (ZZZ, ZZZ)";
+    assert_eq!(Ok(2), day08::day08a(input));
+}